@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_lang::solana_program::hash::hash;
+use anchor_spl::token_2022::spl_token_2022::{self, extension::{BaseStateWithExtensions, StateWithExtensions, transfer_fee::TransferFeeConfig}};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 use core::mem::size_of;
 use solana_security_txt::security_txt;
 
@@ -29,41 +31,71 @@ const INITIAL_TREASURER_ADDRESS: Pubkey = pubkey!("9BRgCdmwyP5wGVTvKAUDjSwucpqGn
 #[cfg(feature = "local")] 
 const INITIAL_TREASURER_ADDRESS: Pubkey = pubkey!("DSLn1ofuSWLbakQWhPUenSBHegwkBBTUwx8ZY4Wfoxm");
 
-const FEE_1CENT: u64 = 1;
-const FEE_3CENTS: u64 = 3;
-const FEE_4CENTS: u64 = 4;
-const FEE_DOLLAR_TREE: u64 = 103;
-
-//Chat Accounts need atleast 119 extra bytes of space to pass with full load
-const CHAT_ACCOUNT_EXTRA_SIZE: usize = 119;
-
-//Poll and poll options need atleast 118 extra bytes of space to pass with full load
-const POLL_AND_POLL_OPTION_EXTRA_SIZE: usize = 144;
-
-//Comment Sections need atleast 9 extra bytes of space to pass with full load with 1 string in seeds
-//Comment Sections need atleast 17 extra bytes of space to pass with full load with 2 string in seeds (now using 2 string seed)
-const COMMENT_SECTION_EXTRA_SIZE: usize = 24;
-
-//Comments and replies need atleast 428 extra bytes of space to pass with full load
-const COMMENT_REPLY_OR_IDEA_EXTRA_SIZE: usize = 470;
-
-//Idea and federal agents need atleast 12 extra bytes of space to pass with full load
-const IDEA_EXTRA_SIZE: usize = 22;
-const FEDERAL_AGENT_EXTRA_SIZE: usize = 22;
-
+//Starting fees in cents, the CEO can change these later with update_fee_schedule
+const DEFAULT_COMMENT_FEE: u64 = 103;
+const DEFAULT_REPLY_FEE: u64 = 4;
+const DEFAULT_EDIT_FEE: u64 = 4;
+const DEFAULT_DELETE_FEE: u64 = 4;
+const DEFAULT_UP_VOTE_FEE: u64 = 3;
+const DEFAULT_DOWN_VOTE_FEE: u64 = 4;
+const DEFAULT_AUTHOR_TIP_SHARE: u64 = 1;
+const DEFAULT_NAME_CHANGE_FEE: u64 = 103;
+const DEFAULT_NAME_FLAG_TOGGLE_FEE: u64 = 4;
+const DEFAULT_VIDEO_VOTE_FEE: u64 = 4;
+const DEFAULT_POLL_VOTE_FEE: u64 = 4;
+
+//Starting reputation weights, the CEO can change these later with update_reputation_config
+const DEFAULT_UP_VOTE_REPUTATION_WEIGHT: i64 = 1;
+const DEFAULT_DOWN_VOTE_REPUTATION_WEIGHT: i64 = -1;
+const DEFAULT_STAR_REPUTATION_WEIGHT: i64 = 100;
+const DEFAULT_FED_REPUTATION_WEIGHT: i64 = -100;
+const DEFAULT_REPUTATION_HALF_LIFE_SECONDS: u64 = 7776000; //90 days
+
+//Accounts with strings derive InitSpace with #[max_len(0)] on each string, so INIT_SPACE only counts the 4 byte length prefixes.
+//The real string lengths are added on top when the account is created or reallocated, so accounts are exactly as big as their content.
+
+//Moderator permission bits
+const MODERATOR_PERMISSION_STAR: u8 = 1;
+const MODERATOR_PERMISSION_FED: u8 = 2;
+const MODERATOR_PERMISSION_DISABLE_SECTION: u8 = 4;
+const MODERATOR_PERMISSION_MANAGE_POLLS: u8 = 8;
+const MODERATOR_PERMISSION_MANAGE_IDEAS: u8 = 16;
+const MODERATOR_PERMISSION_DELETE_POSTS: u8 = 32;
+const ALL_MODERATOR_PERMISSIONS: u8 = 63;
+
+//Protocol pause bits
+const PAUSE_POSTING: u8 = 1;
+const PAUSE_VOTING: u8 = 2;
+const PAUSE_FEES: u8 = 4;
+const PAUSE_POLLS: u8 = 8;
+const ALL_PAUSE_FLAGS: u8 = 15;
+
+//Post tombstone flag bits, what a tombstone keeps of the deleted post's state
+const TOMBSTONE_FLAG_STARRED: u8 = 1;
+const TOMBSTONE_FLAG_FED: u8 = 2;
+const TOMBSTONE_FLAG_PINNED: u8 = 4;
+const TOMBSTONE_FLAG_HIDDEN: u8 = 8;
+const TOMBSTONE_FLAG_REMOVED: u8 = 16; //Removed by a moderator instead of deleted by its author
+
+//Hot scores work like Reddit's, each 10x in net votes is worth this many seconds of newness
+const HOT_SCORE_SECONDS_PER_ORDER: u128 = 45000;
+
+//How many trending posts each comment section keeps
+const TRENDING_POST_COUNT: usize = 5;
+
+//Vote pricing modes, quadratic pricing charges N squared fee units for N votes
+const VOTE_PRICING_LINEAR: u8 = 0;
+const VOTE_PRICING_QUADRATIC: u8 = 1;
+
+//Fees are stored in cents, so fee tokens need atleast 2 decimals
+const MIN_FEE_TOKEN_DECIMAL_AMOUNT: u8 = 2;
+
+const MAX_CHAT_NAME_LENGTH: usize = 32;
 const MAX_COMMENT_SECTION_PREFIX_OR_NAME_LENGTH: usize = 32;
 const MAX_POLL_AND_POLL_OPTION_NAME_LENGTH: usize = 144;
 const MAX_CUSTOM_USER_NAME_LENGTH: usize = 144;
 const MAX_POST_LENGTH: usize = 444;
 
-enum PostType
-{
-    Comment = 0,
-    Reply = 1,
-    Lv3Reply = 2,
-    Lv4Reply = 3
-}
-
 //Error Codes
 #[error_code]
 pub enum AuthorizationError 
@@ -72,10 +104,20 @@ pub enum AuthorizationError
     NotCEO,
     #[msg("Only the Treasurer can call this function")]
     NotTreasurer,
-    #[msg("This comment isn't yours to change")]
-    NotCommentOwner,
-    #[msg("This reply isn't yours to change")]
-    NotReplyOwner
+    #[msg("Only the CEO or a moderator with permission can call this function")]
+    NotCEOOrModerator,
+    #[msg("Only the CEO, a moderator with permission or the comment section owner can call this function")]
+    NotCEOModeratorOrSectionOwner,
+    #[msg("Only the comment section owner or the CEO can call this function")]
+    NotSectionOwner,
+    #[msg("Only a comment section moderator, the comment section owner or the CEO can call this function")]
+    NotSectionModerator,
+    #[msg("Only the pending CEO can accept the title")]
+    NotPendingCEO,
+    #[msg("Only the pending Treasurer can accept the title")]
+    NotPendingTreasurer,
+    #[msg("This post isn't yours to change")]
+    NotPostOwner
 }  
 
 #[error_code]
@@ -90,7 +132,69 @@ pub enum InvalidOperationError
     #[msg("Can't set flag to the same state")]
     FlagSameState,
     #[msg("Can't delete poll that still has options, please delete remaining options first")]
-    PollStillHasOptions
+    PollStillHasOptions,
+    #[msg("Can't reply any deeper in this chat")]
+    MaxPostDepthReached,
+    #[msg("This comment section is disabled")]
+    CommentSectionDisabled,
+    #[msg("This poll is not active")]
+    PollNotActive,
+    #[msg("This poll option is not active")]
+    PollOptionNotActive,
+    #[msg("Fee token must have atleast 2 decimals")]
+    UnsupportedFeeTokenDecimals,
+    #[msg("Fee amount is too large")]
+    FeeAmountOverflow,
+    #[msg("Quadratic vote pricing needs the voter's vote tally account")]
+    MissingVoteTally,
+    #[msg("There is no pending title handover")]
+    NoPendingHandover,
+    #[msg("Moderator permissions must be non 0 and only use known permission bits")]
+    InvalidModeratorPermissions,
+    #[msg("This part of the protocol is paused")]
+    ProtocolPaused,
+    #[msg("Paused flags can only use known pause bits")]
+    InvalidPausedFlags,
+    #[msg("The post's idea or FED record must be passed in so its copy can be cleared")]
+    MissingPostCopyAccount,
+    #[msg("Only deleted posts can be closed")]
+    PostNotDeleted,
+    #[msg("The parent post or its tombstone must be passed in when closing a reply")]
+    MissingParentPost,
+    #[msg("The post tombstone must be passed in when closing a post that still has replies")]
+    MissingPostTombstone,
+    #[msg("A tombstone can only be closed once it has no replies left")]
+    TombstoneHasReplies,
+    #[msg("The edit window for this post has closed")]
+    EditWindowClosed,
+    #[msg("Close the post's revisions before closing the post")]
+    PostHasRevisions,
+    #[msg("Starred posts can't be edited")]
+    StarredPostEditLocked,
+    #[msg("FED posts can't be edited")]
+    FEDPostEditLocked,
+    #[msg("This chat uses changeable votes, use set_post_vote instead")]
+    ChangeableVotesOnly,
+    #[msg("This chat doesn't use changeable votes")]
+    ChangeableVotesDisabled,
+    #[msg("Your vote is already set to this amount")]
+    VoteUnchanged,
+    #[msg("Unknown vote pricing mode")]
+    InvalidVotePricingMode,
+    #[msg("Reputation amount is too large")]
+    ReputationOverflow,
+    #[msg("A poll must close after it opens")]
+    InvalidPollWindow,
+    #[msg("This poll hasn't opened yet")]
+    PollNotOpen,
+    #[msg("This poll has closed")]
+    PollClosed,
+    #[msg("This poll can't be finalized until it closes")]
+    PollStillOpen,
+    #[msg("This poll has been finalized")]
+    PollFinalized,
+    #[msg("Vote amount is too large")]
+    VoteAmountTooLarge
 }
 
 #[error_code]
@@ -104,3432 +208,3145 @@ pub enum InvalidLengthError
     CommentSectionNamePrefixTooLong,
     #[msg("Comment section name can't be longer than 32 characters")]
     CommentSectionNameTooLong,
+    #[msg("Chat name can't be longer than 32 characters")]
+    ChatNameTooLong,
     #[msg("Message can't be longer than 444 characters")]
     MSGTooLong,
 } 
 
-//Helper function to handle the Stable Coin fee transfer
-fn apply_fee<'info>(
-    from_account: AccountInfo<'info>,
-    to_account: AccountInfo<'info>,
-    signer: AccountInfo<'info>,
-    token_program: AccountInfo<'info>,
-    treasurer: Account<ChatProtocolTreasurer>,
-    amount: u64,
-    decimal_amount: u8
-) -> Result<()> {
-    let cpi_accounts = token::Transfer {
-        from: from_account,
-        to: to_account,
-        authority: signer
-    };
-    let cpi_program = token_program;
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+//Events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum AdminRole
+{
+    CEO,
+    Treasurer
+}
 
-    let base_int :u64 = 10;
-    let conversion_number = base_int.pow(decimal_amount as u32 - 2); //Convert fixed point cents to native token decimal amount
-    let fixed_pointed_notation_amount = amount * conversion_number;
+#[event]
+pub struct PostCreated
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub id: u128,
+    pub parent_id: u128,
+    pub depth: u8,
+    pub msg: String,
+    pub unix_creation_time_stamp: u64
+}
 
-    //Transfer fee to Treasurer Wallet
-    token::transfer(cpi_ctx, fixed_pointed_notation_amount)?;
+#[event]
+pub struct PostEdited
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8,
+    pub msg: String
+}
 
-    msg!("Successfully transferred ${:.2} as fee to: {}", amount as f64 / 100.0, treasurer.address);
+#[event]
+pub struct PostDeleted
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8
+}
 
-    Ok(())
+#[event]
+pub struct PostRemoved
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8,
+    pub removed_by_address: Pubkey,
+    pub removal_reason_code: u8
 }
 
-//Helper function to handle the Stable Coin fee transfer to user receiveing up votes
-fn send_turd_of_tree<'info>(
-    from_account: AccountInfo<'info>,
-    to_account: AccountInfo<'info>,
-    signer: AccountInfo<'info>,
-    token_program: AccountInfo<'info>,
-    post_owner_address: Pubkey,
-    amount: u64,
-    decimal_amount: u8
-) -> Result<()> {
-    let cpi_accounts = token::Transfer {
-        from: from_account,
-        to: to_account,
-        authority: signer
-    };
-    let cpi_program = token_program;
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+#[event]
+pub struct VoteCast
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub voter_address: Pubkey,
+    pub canidate_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8,
+    pub vote_amount: i128,
+    pub net_vote_score: i128
+}
 
-    let base_int :u64 = 10;
-    let conversion_number = base_int.pow(decimal_amount as u32 - 2); //Convert fixed point cents to native token decimal amount
-    let fixed_pointed_notation_amount = amount * conversion_number;
+#[event]
+pub struct Starred
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8,
+    pub is_starred: bool
+}
 
-    //Transfer fee to Post Owner Wallet
-    token::transfer(cpi_ctx, fixed_pointed_notation_amount)?;
+#[event]
+pub struct IdeaImplemented
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub is_implemented: bool,
+    pub implementation_time: u64
+}
 
-    msg!("Successfully transferred ${:.2} as fee to: {}", amount as f64 / 100.0, post_owner_address.key());
+#[event]
+pub struct FedMarked
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub post_owner_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub depth: u8,
+    pub is_fed: bool
+}
 
-    Ok(())
+#[event]
+pub struct PollVoteCast
+{
+    pub voter_address: Pubkey,
+    pub poll_index: u128,
+    pub poll_option_index: u8,
+    pub vote_amount: i128
 }
 
-//Functions
-#[program]
-pub mod chat
+#[event]
+pub struct PollFinalized
 {
-    use super::*;
+    pub poll_index: u128,
+    pub up_vote_score: u128,
+    pub down_vote_score: u128,
+    pub up_vote_count: u128,
+    pub down_vote_count: u128,
+    pub unix_finalized_time_stamp: u64
+}
 
-    pub fn initialize_chat_protocol_admin_accounts(ctx: Context<InitializeAdminAccounts>) -> Result<()> 
+#[event]
+pub struct FeeCharged
+{
+    pub payer_address: Pubkey,
+    pub recipient_address: Pubkey,
+    pub amount_in_cents: u64,
+    pub token_amount: u64
+}
+
+#[event]
+pub struct AdminTransferred
+{
+    pub role: AdminRole,
+    pub previous_address: Pubkey,
+    pub new_address: Pubkey
+}
+
+//Helper function to check if the signer is the CEO or a moderator holding the permission
+fn is_ceo_or_moderator(signer: &Signer, ceo: &ChatProtocolCEO, moderator: &Option<Account<Moderator>>, permission: u8) -> bool {
+    if signer.key() == ceo.address.key()
     {
-        //Only the initial CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), INITIAL_CEO_ADDRESS, AuthorizationError::NotCEO);
+        return true;
+    }
 
-        let ceo = &mut ctx.accounts.ceo;
-        ceo.address = INITIAL_CEO_ADDRESS;
+    match moderator
+    {
+        Some(moderator) => moderator.permissions & permission == permission,
+        None => false
+    }
+}
 
-        let treasurer = &mut ctx.accounts.treasurer;
-        treasurer.address = INITIAL_TREASURER_ADDRESS;
+//Helper function that fails with NotCEOOrModerator unless the signer is the CEO or a moderator with the permission
+fn require_ceo_or_moderator(signer: &Signer, ceo: &ChatProtocolCEO, moderator: &Option<Account<Moderator>>, permission: u8) -> Result<()> {
+    require!(is_ceo_or_moderator(signer, ceo, moderator, permission), AuthorizationError::NotCEOOrModerator);
 
-        msg!("Chat Protocol Admin Accounts Initialized");
-        msg!("New CEO Address: {}", ceo.address.key());
-        msg!("New Treasurer Address: {}", treasurer.address.key());
+    Ok(())
+}
 
-        Ok(())
-    }
+//Helper function to check if any of the given pause bits are set
+fn is_protocol_paused(protocol_config: &ProtocolConfig, pause_flags: u8) -> bool {
+    protocol_config.paused_flags & pause_flags != 0
+}
 
-    pub fn pass_on_chat_protocol_ceo(ctx: Context<PassOnChatProtocolCEO>, new_ceo_address: Pubkey) -> Result<()> 
+//Helper function to clear the idea and FED record copies of a deleted post unless the protocol config says to keep them
+fn clear_deleted_post_copies(protocol_config: &ProtocolConfig, post: &Post, idea: &mut Option<Box<Account<Idea>>>, fed_record: &mut Option<Box<Account<FEDRecord>>>) -> Result<()> {
+    if !protocol_config.keep_idea_copy_on_delete
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        //Starred posts have an idea, so it must be passed in to be cleared
+        require!(post.is_starred == false || idea.is_some(), InvalidOperationError::MissingPostCopyAccount);
 
-        msg!("The Chat Protocol CEO has passed on the title to a new CEO");
-        msg!("New CEO: {}", new_ceo_address.key());
+        if let Some(idea) = idea
+        {
+            idea.idea = String::new();
+        }
+    }
 
-        ceo.address = new_ceo_address.key();
+    if !protocol_config.keep_fed_record_copy_on_delete
+    {
+        //FED posts have a FED record, so it must be passed in to be cleared
+        require!(post.is_fed == false || fed_record.is_some(), InvalidOperationError::MissingPostCopyAccount);
 
-        Ok(())
+        if let Some(fed_record) = fed_record
+        {
+            fed_record.post = String::new();
+        }
     }
 
-    pub fn pass_on_chat_protocol_treasurer(ctx: Context<PassOnChatProtocolTreasurer>, new_treasurer_address: Pubkey) -> Result<()> 
-    {
-        let treasurer = &mut ctx.accounts.treasurer;
-        //Only the Treasurer can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
+    Ok(())
+}
 
-        msg!("The Chat Protocol Treasurer has passed on the title to a new Treasurer");
-        msg!("New Treasurer: {}", new_treasurer_address.key());
+//Helper function to check that a poll's window closes after it opens, 0 means the poll opens or closes with no time limit
+fn is_valid_poll_window(opens_at: u64, closes_at: u64) -> bool {
+    closes_at == 0 || closes_at > opens_at
+}
 
-        treasurer.address = new_treasurer_address.key();
+//Helper function to get the number of votes in a signed vote amount, votes are charged per vote so they must fit in a u64
+fn get_vote_count(vote_amount: i128) -> Result<u64> {
+    Ok(u64::try_from(vote_amount.unsigned_abs()).map_err(|_| InvalidOperationError::VoteAmountTooLarge)?)
+}
 
-        Ok(())
+//Helper function to get how many fee units a vote costs, quadratic pricing counts the votes the voter already cast on the same target
+fn get_vote_cost_units(vote_pricing_mode: u8, votes_already_cast: u64, vote_amount: u64) -> Result<u64> {
+    if vote_pricing_mode != VOTE_PRICING_QUADRATIC
+    {
+        return Ok(vote_amount);
     }
 
-    pub fn add_fee_token_entry(ctx: Context<AddFeeTokenEntry>, token_mint_address: Pubkey, decimal_amount: u8) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+    let total_votes = votes_already_cast.checked_add(vote_amount).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+    let total_cost = total_votes.checked_mul(total_votes).ok_or(InvalidOperationError::FeeAmountOverflow)?;
 
-        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
-        fee_token_entry.token_mint_address = token_mint_address;
-        fee_token_entry.decimal_amount = decimal_amount;
+    Ok(total_cost - votes_already_cast * votes_already_cast)
+}
 
-        msg!("Added Fee Token Entry");
-        msg!("Mint Address: {}", token_mint_address.key());
-        msg!("Decimal Amount: {}", decimal_amount);
-            
-        Ok(())
+//Helper function to get the part of the fees paid for a ballot's votes that goes with the votes being taken off, quadratic pricing gives the removed votes the units they cost on top of the votes that are left
+fn get_removed_vote_fees(vote_pricing_mode: u8, fees_paid: u64, old_vote_count: u64, new_vote_count: u64) -> Result<u64> {
+    if new_vote_count >= old_vote_count
+    {
+        return Ok(0);
     }
 
-    pub fn remove_fee_token_entry(ctx: Context<RemoveFeeTokenEntry>, token_mint_address: Pubkey) -> Result<()> 
+    let (old_units, new_units) = if vote_pricing_mode == VOTE_PRICING_QUADRATIC
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        msg!("Removed Fee Token Entry");
-        msg!("Mint Address: {}", token_mint_address.key());
-            
-        Ok(())
+        (old_vote_count as u128 * old_vote_count as u128, new_vote_count as u128 * new_vote_count as u128)
     }
-
-    pub fn initialize_quality_of_life_accounts(ctx: Context<InitializeQualityOfLifeAccounts>) -> Result<()> 
+    else
     {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+        (old_vote_count as u128, new_vote_count as u128)
+    };
 
-        msg!("Quality of life accounts initialized");
+    let removed_vote_fees = (fees_paid as u128).checked_mul(old_units - new_units).ok_or(InvalidOperationError::FeeAmountOverflow)? / old_units;
 
-        Ok(())
-    }
+    //Never more than the fees paid, so it fits back in a u64
+    Ok(removed_vote_fees as u64)
+}
 
-    pub fn initialize_chat_protocol(ctx: Context<InitializeChatProtocol>) -> Result<()> 
-    {
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        chat_protocol.chat_protocol_initiator_address = ctx.accounts.signer.key();
+//Helper function to add a vote to the voter's tally for a target and return how many fee units it costs, the tally is only kept under quadratic pricing
+fn add_to_vote_tally(vote_tally: &mut Option<Box<Account<VoteTally>>>, target_address: Pubkey, voter_address: Pubkey, vote_pricing_mode: u8, vote_amount: i128) -> Result<u64> {
+    let vote_count = get_vote_count(vote_amount)?;
 
-        msg!("Chat Protocol Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
-        Ok(())
+    //Linear pricing charges the same for every vote, so it doesn't need the tally
+    if vote_pricing_mode != VOTE_PRICING_QUADRATIC
+    {
+        return Ok(vote_count);
     }
 
-    pub fn initialize_m4a_chat(ctx: Context<InitializeM4AChat>) -> Result<()> 
-    {
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        m4a_chat.chat_initiator_address = ctx.accounts.signer.key();
+    let vote_tally = vote_tally.as_mut().ok_or(InvalidOperationError::MissingVoteTally)?;
+    vote_tally.target_address = target_address;
+    vote_tally.voter_address = voter_address;
 
-        msg!("M4A Chat Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
-        Ok(())
+    if vote_amount > 0
+    {
+        let vote_cost_units = get_vote_cost_units(vote_pricing_mode, vote_tally.up_vote_total, vote_count)?;
+        vote_tally.up_vote_total = vote_tally.up_vote_total.checked_add(vote_count).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+        Ok(vote_cost_units)
     }
-
-    pub fn initialize_pli_chat(ctx: Context<InitializePLIChat>) -> Result<()> 
+    else
     {
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        pli_chat.chat_initiator_address = ctx.accounts.signer.key();
-
-        msg!("PLI Chat Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
-        Ok(())
+        let vote_cost_units = get_vote_cost_units(vote_pricing_mode, vote_tally.down_vote_total, vote_count)?;
+        vote_tally.down_vote_total = vote_tally.down_vote_total.checked_add(vote_count).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+        Ok(vote_cost_units)
     }
+}
 
-    pub fn initialize_about_chat(ctx: Context<InitializeAboutChat>) -> Result<()> 
+//Helper function to add to or subtract from a vote tally
+fn adjust_tally(tally: &mut u128, amount: u128, is_adding: bool) -> Result<()> {
+    *tally = if is_adding
     {
-        let about_chat = &mut ctx.accounts.about_chat;
-        about_chat.chat_initiator_address = ctx.accounts.signer.key();
-
-        msg!("About Chat Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
-        Ok(())
+        tally.checked_add(amount)
     }
-
-    pub fn initialize_lo_chat(ctx: Context<InitializeLOChat>) -> Result<()> 
+    else
     {
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        lo_chat.chat_initiator_address = ctx.accounts.signer.key();
+        tally.checked_sub(amount)
+    }.ok_or(InvalidOperationError::VoteAmountTooLarge)?;
 
-        msg!("LO Chat Initialized");
-        msg!("Initialized By User: {}", ctx.accounts.signer.key());
-        Ok(())
+    Ok(())
+}
+
+//Helper function to get a post's hot score, newer posts start higher so old posts need more votes to stay on top
+fn get_hot_score(net_vote_score: i128, unix_creation_time_stamp: u64) -> i128 {
+    let vote_count = net_vote_score.unsigned_abs().max(1);
+    let order = vote_count.ilog10();
+    let order_start = 10u128.pow(order);
+
+    //Whole orders of magnitude plus a linear step within the order, an integer stand in for log10
+    let order_step = (vote_count - order_start).saturating_mul(HOT_SCORE_SECONDS_PER_ORDER) / order_start.saturating_mul(9);
+    let vote_seconds = (order as u128 * HOT_SCORE_SECONDS_PER_ORDER + order_step) as i128;
+
+    if net_vote_score < 0
+    {
+        unix_creation_time_stamp as i128 - vote_seconds
+    }
+    else
+    {
+        unix_creation_time_stamp as i128 + vote_seconds
     }
+}
 
-    pub fn create_chat_account(ctx: Context<CreateChatAccount>) -> Result<()> 
+//Helper function to put a post in its place on the comment section's trending list, or take it off the list
+fn update_trending_posts(comment_section: &mut CommentSection, post_address: Pubkey, hot_score: i128, is_trending: bool) {
+    let mut trending_posts: Vec<TrendingPost> = comment_section.trending_posts.iter()
+        .filter(|trending_post| trending_post.post_address != Pubkey::default() && trending_post.post_address != post_address)
+        .copied()
+        .collect();
+
+    if is_trending
     {
-        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
-        let chat_account = &mut ctx.accounts.chat_account;
+        trending_posts.push(TrendingPost { post_address, hot_score });
+    }
 
-        chat_account_stats.chat_account_count += 1;
-        chat_account.id = chat_account_stats.chat_account_count;
-        chat_account.user_address = ctx.accounts.signer.key();
+    trending_posts.sort_by_key(|trending_post| std::cmp::Reverse(trending_post.hot_score));
+    trending_posts.truncate(TRENDING_POST_COUNT);
 
-        msg!("Chat Account Created For: {}", ctx.accounts.signer.key());
-        msg!("Chat Account Number: {}", chat_account_stats.chat_account_count);
+    comment_section.trending_posts = [TrendingPost::default(); TRENDING_POST_COUNT];
+    comment_section.trending_posts[..trending_posts.len()].copy_from_slice(&trending_posts);
+}
 
-        Ok(())
+//Helper function to decay reputation towards 0, it halves once for every whole half life so decay composes however often it is applied
+fn get_decayed_reputation(reputation: i128, half_lives: u64) -> i128 {
+    if half_lives >= 127
+    {
+        return 0;
     }
 
-    pub fn create_comment_section(ctx: Context<CreateCommentSection>,
-        comment_section_name_prefix: String,
-        comment_section_name: String) -> Result<()> 
+    reputation / (1i128 << half_lives)
+}
+
+//Helper function to count the half lives that end between two time stamps, half lives are counted from the chat account's reputation time stamp so every change decays on the account's schedule
+fn get_half_lives_between(reputation_time_stamp: u64, from_time_stamp: u64, to_time_stamp: u64, half_life_seconds: u64) -> u64 {
+    let half_life_seconds = half_life_seconds as i128;
+    let from_half_life = (from_time_stamp as i128 - reputation_time_stamp as i128).div_euclid(half_life_seconds);
+    let to_half_life = (to_time_stamp as i128 - reputation_time_stamp as i128).div_euclid(half_life_seconds);
+
+    (to_half_life - from_half_life).max(0) as u64
+}
+
+//Helper function to get what is left of a past reputation change after decay, so undoing it only takes back what the account still has
+fn get_remaining_reputation_change(reputation_config: &ReputationConfig, reputation_change: i128, applied_time_stamp: u64, reputation_time_stamp: u64) -> Result<i128> {
+    //A half life of 0 turns decay off
+    if reputation_config.decay_half_life_seconds == 0
     {
-        //Comment section prefix name string must not be longer than 32 characters
-        require!(comment_section_name_prefix.len() <= MAX_COMMENT_SECTION_PREFIX_OR_NAME_LENGTH, InvalidLengthError::CommentSectionNamePrefixTooLong);
+        return Ok(reputation_change);
+    }
 
-        //Comment section name string must not be longer than 32 characters
-        require!(comment_section_name.len() <= MAX_COMMENT_SECTION_PREFIX_OR_NAME_LENGTH, InvalidLengthError::CommentSectionNameTooLong);
+    let time_stamp = Clock::get()?.unix_timestamp as u64;
+    let half_lives = get_half_lives_between(reputation_time_stamp, applied_time_stamp, time_stamp, reputation_config.decay_half_life_seconds);
 
-        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
-        let comment_section = &mut ctx.accounts.comment_section;
+    Ok(get_decayed_reputation(reputation_change, half_lives))
+}
 
-        comment_section_stats.comment_section_count += 1;
-        comment_section.id = comment_section_stats.comment_section_count;
-        comment_section.comment_section_initiator_address = ctx.accounts.signer.key();
-        comment_section.comment_section_name_prefix = comment_section_name_prefix.clone();
-        comment_section.comment_section_name = comment_section_name.clone();
+//Helper function to get how much reputation a vote gives the post owner
+fn get_vote_reputation_change(reputation_config: &ReputationConfig, vote_amount: i128) -> Result<i128> {
+    let vote_weight = if vote_amount > 0 { reputation_config.up_vote_weight } else { reputation_config.down_vote_weight };
 
-        msg!("New Comment Section Created");
-        msg!("Comment Section Count: {}", comment_section_stats.comment_section_count);
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Created By User: {}", ctx.accounts.signer.key());
+    let vote_count = vote_amount.checked_abs().ok_or(InvalidOperationError::ReputationOverflow)?;
 
-        let chat_account = &mut ctx.accounts.chat_account;
-        if !chat_account.has_good_ending
-        {
-            chat_account.has_good_ending = true;
-        }
-       
-        Ok(())
-    }
+    Ok(vote_count.checked_mul(vote_weight as i128).ok_or(InvalidOperationError::ReputationOverflow)?)
+}
 
-    pub fn set_comment_section_flag(ctx: Context<SetCommentSectionFlag>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        is_enabled: bool) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
+//Helper function to decay a chat account's reputation up to now and then apply a change to it
+fn update_reputation(chat_account: &mut ChatAccount, reputation_config: &ReputationConfig, reputation_change: i128) -> Result<()> {
+    let time_stamp = Clock::get()?.unix_timestamp as u64;
+    let half_life_seconds = reputation_config.decay_half_life_seconds;
 
-        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
-        let comment_section = &mut ctx.accounts.comment_section;
+    //A half life of 0 turns decay off
+    if half_life_seconds == 0
+    {
+        chat_account.reputation = chat_account.reputation.checked_add(reputation_change).ok_or(InvalidOperationError::ReputationOverflow)?;
+        chat_account.reputation_update_time_stamp = time_stamp;
+        return Ok(());
+    }
 
-        comment_section_stats.toggle_flag_count += 1;
+    //The time stamp only moves forward by whole half lives, so the part of a half life that has not ended yet still counts towards the next one
+    let half_lives = time_stamp.saturating_sub(chat_account.reputation_update_time_stamp) / half_life_seconds;
+    let decayed_reputation = get_decayed_reputation(chat_account.reputation, half_lives);
 
-        //Can't set flag to the same state
-        require!(comment_section.is_disabled != is_enabled, InvalidOperationError::FlagSameState);
+    chat_account.reputation = decayed_reputation.checked_add(reputation_change).ok_or(InvalidOperationError::ReputationOverflow)?;
+    chat_account.reputation_update_time_stamp += half_lives * half_life_seconds;
 
-        comment_section.is_disabled = is_enabled;
+    Ok(())
+}
 
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Disabled Flag Updated To: {}", is_enabled);
-        
-        Ok(())
+//Helper function to add or remove a changeable vote from all of the post vote tallies
+fn update_post_vote_tallies(accounts: &mut SetPostVote, is_self_vote: bool, vote_amount: i128, is_adding: bool) -> Result<()> {
+    if vote_amount == 0
+    {
+        return Ok(());
     }
 
-    //This vote could be for a video, or what ever is on the page of the comment section
-    pub fn comment_section_vote(ctx: Context<CommentSectionVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
+    let post_vote_stats = &mut accounts.post_vote_stats;
+    let chat = &mut accounts.chat;
+    let comment_section = &mut accounts.comment_section;
+    let canidate_chat_account = &mut accounts.canidate_chat_account;
+    let voter_chat_account = &mut accounts.voter_chat_account;
+    let post = &mut accounts.post;
 
-        let mut is_up_vote = false;
+    let vote_score = vote_amount.unsigned_abs();
 
+    //Account for voter voting for their own post since can't duplicate accounts
+    if is_self_vote
+    {
         if vote_amount > 0
         {
-            is_up_vote = true;
+            adjust_tally(&mut voter_chat_account.received_up_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut voter_chat_account.up_vote_received_count, 1, is_adding)?;
         }
+        else
+        {
+            adjust_tally(&mut voter_chat_account.received_down_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut voter_chat_account.down_vote_received_count, 1, is_adding)?;
+        }
+    }
 
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let video_vote_stats = &mut ctx.accounts.video_vote_stats;
-        let video_vote_record = &mut ctx.accounts.video_vote_record;
+    if vote_amount > 0
+    {
+        adjust_tally(&mut post_vote_stats.post_up_vote_count, 1, is_adding)?;
+        adjust_tally(&mut comment_section.post_up_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut comment_section.post_up_vote_count, 1, is_adding)?;
 
-        if !chat_account.has_good_ending
+        if post.depth == 0
         {
-            chat_account.has_good_ending = true;
+            adjust_tally(&mut chat.comment_up_vote_count, 1, is_adding)?;
+            adjust_tally(&mut comment_section.comment_up_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut comment_section.comment_up_vote_count, 1, is_adding)?;
         }
-    
-        if is_up_vote
+        else
         {
-            video_vote_stats.video_up_vote_count += 1;
-            comment_section_stats.video_up_vote_count += 1;
-            comment_section.video_up_vote_score += vote_amount as u128;
-            comment_section.video_up_vote_count += 1;
-        
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted Comment Section Video");
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
+            adjust_tally(&mut chat.reply_up_vote_count, 1, is_adding)?;
+            adjust_tally(&mut comment_section.reply_up_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut comment_section.reply_up_vote_count, 1, is_adding)?;
+        }
+
+        adjust_tally(&mut voter_chat_account.casted_up_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut voter_chat_account.up_vote_casted_count, 1, is_adding)?;
+        adjust_tally(&mut canidate_chat_account.received_up_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut canidate_chat_account.up_vote_received_count, 1, is_adding)?;
+    }
+    else
+    {
+        adjust_tally(&mut post_vote_stats.post_down_vote_count, 1, is_adding)?;
+        adjust_tally(&mut comment_section.post_down_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut comment_section.post_down_vote_count, 1, is_adding)?;
+
+        if post.depth == 0
+        {
+            adjust_tally(&mut chat.comment_down_vote_count, 1, is_adding)?;
+            adjust_tally(&mut comment_section.comment_down_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut comment_section.comment_down_vote_count, 1, is_adding)?;
         }
         else
         {
-            video_vote_stats.video_down_vote_count += 1;
-            comment_section_stats.video_down_vote_count += 1;
-            comment_section.video_down_vote_score += vote_amount.abs() as u128;
-            comment_section.video_down_vote_count += 1;
-            
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted Comment Section Video");
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
+            adjust_tally(&mut chat.reply_down_vote_count, 1, is_adding)?;
+            adjust_tally(&mut comment_section.reply_down_vote_score, vote_score, is_adding)?;
+            adjust_tally(&mut comment_section.reply_down_vote_count, 1, is_adding)?;
         }
 
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        video_vote_record.protocol_record_id = comment_section_stats.video_up_vote_count + comment_section_stats.video_down_vote_count;
-        video_vote_record.comment_section_record_id = comment_section.video_up_vote_count + comment_section.video_down_vote_count;
-        video_vote_record.voter_address = ctx.accounts.signer.key();
-        video_vote_record.comment_section_name_prefix = comment_section_name_prefix;
-        video_vote_record.comment_section_name = comment_section_name;
-        
-        video_vote_record.vote_amount = vote_amount;
-        video_vote_record.unix_creation_time_stamp = time_stamp;
+        adjust_tally(&mut voter_chat_account.casted_down_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut voter_chat_account.down_vote_casted_count, 1, is_adding)?;
+        adjust_tally(&mut canidate_chat_account.received_down_vote_score, vote_score, is_adding)?;
+        adjust_tally(&mut canidate_chat_account.down_vote_received_count, 1, is_adding)?;
+    }
 
-        chat_account.video_vote_count += 1;
+    post.net_vote_score = if is_adding
+    {
+        post.net_vote_score.checked_add(vote_amount)
+    }
+    else
+    {
+        post.net_vote_score.checked_sub(vote_amount)
+    }.ok_or(InvalidOperationError::VoteAmountTooLarge)?;
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+    Ok(())
+}
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_ata.to_account_info(),
-            accounts.treasurer_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS * vote_amount.abs() as u64,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+//Helper function to check if the signer is the CEO or the owner of the comment section
+fn is_ceo_or_section_owner(signer: &Signer, ceo: &ChatProtocolCEO, comment_section: &CommentSection) -> bool {
+    signer.key() == ceo.address.key() || signer.key() == comment_section.owner_address.key()
+}
 
-        Ok(())
-    }
+//Helper function to pack the state a tombstone keeps of a deleted post into tombstone flag bits
+fn get_tombstone_flags(post: &Post) -> u8 {
+    let mut flags = 0;
 
-    pub fn update_user_name(ctx: Context<UpdateUserName>, _token_mint_address: Pubkey, user_name: String) -> Result<()> 
+    if post.is_starred
     {
-        //User Name string must not be longer than 144 characters
-        require!(user_name.len() <= MAX_CUSTOM_USER_NAME_LENGTH, InvalidLengthError::UserNameTooLong);
+        flags |= TOMBSTONE_FLAG_STARRED;
+    }
+    if post.is_fed
+    {
+        flags |= TOMBSTONE_FLAG_FED;
+    }
+    if post.is_pinned
+    {
+        flags |= TOMBSTONE_FLAG_PINNED;
+    }
+    if post.is_hidden
+    {
+        flags |= TOMBSTONE_FLAG_HIDDEN;
+    }
+    if post.removed_by_address != Pubkey::default()
+    {
+        flags |= TOMBSTONE_FLAG_REMOVED;
+    }
 
-        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
-        chat_account_stats.updated_name_count += 1;
+    flags
+}
 
-        let chat_account = &mut ctx.accounts.chat_account;
-        chat_account.user_name = user_name.clone();
-        chat_account.use_custom_name = true;
+//Helper function to take a closed reply off its parent's reply count, the parent is either still a post or already a tombstone
+fn remove_reply_from_parent(parent_post: &mut Option<Box<Account<Post>>>, parent_post_tombstone: &mut Option<Box<Account<PostTombstone>>>) -> Result<()> {
+    if let Some(parent_post) = parent_post
+    {
+        parent_post.reply_count -= 1;
+        return Ok(());
+    }
 
-        if chat_account.has_had_custom_name == false
-        {
-            chat_account.has_had_custom_name = true
-        }
+    let parent_post_tombstone = parent_post_tombstone.as_mut().ok_or(InvalidOperationError::MissingParentPost)?;
+    parent_post_tombstone.reply_count -= 1;
 
-        msg!("User Name Updated For: {}", ctx.accounts.signer.key());
-        msg!("User Name: {}", user_name);
+    Ok(())
+}
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+//Helper function to convert fixed point cents to native token decimal amount
+fn convert_cents_to_token_amount(amount: u64, decimal_amount: u8) -> Result<u64> {
+    let decimal_difference = decimal_amount.checked_sub(MIN_FEE_TOKEN_DECIMAL_AMOUNT).ok_or(InvalidOperationError::UnsupportedFeeTokenDecimals)?;
+    let conversion_number = 10u64.checked_pow(decimal_difference as u32).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+    let fixed_pointed_notation_amount = amount.checked_mul(conversion_number).ok_or(InvalidOperationError::FeeAmountOverflow)?;
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_ata.to_account_info(),
-            accounts.treasurer_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_DOLLAR_TREE,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+    Ok(fixed_pointed_notation_amount)
+}
 
-        Ok(())
-    }
+//Helper function to add the mint's transfer fee on top of the amount so the recipient still gets the full amount
+fn add_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let mint_data = mint.try_borrow_data()?;
+    let mint_with_extensions = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
 
-    pub fn set_use_custom_name_flag(ctx: Context<SetUseCustomNameFlag>, _token_mint_address: Pubkey, is_enabled: bool, ) -> Result<()> 
+    //Legacy SPL Token mints and Token-2022 mints without the transfer fee extension don't charge anything extra
+    if let Ok(transfer_fee_config) = mint_with_extensions.get_extension::<TransferFeeConfig>()
     {
-        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
-        chat_account_stats.set_flag_count += 1;
-
-        let chat_account = &mut ctx.accounts.chat_account;
-        //Can't set flag to the same state
-        require!(chat_account.use_custom_name != is_enabled, InvalidOperationError::FlagSameState);
+        let epoch = Clock::get()?.epoch;
+        let transfer_fee = transfer_fee_config.calculate_inverse_epoch_fee(epoch, amount).ok_or(InvalidOperationError::FeeAmountOverflow)?;
 
-        chat_account.use_custom_name = is_enabled;
+        return Ok(amount.checked_add(transfer_fee).ok_or(InvalidOperationError::FeeAmountOverflow)?);
+    }
 
-        msg!("User Name Flag Updated For: {}", ctx.accounts.signer.key());
-        msg!("User Name Flag: {}", is_enabled);
+    Ok(amount)
+}
 
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
+//Helper function to handle the Stable Coin fee transfer to the treasury vault
+fn apply_fee<'info>(
+    from_account: AccountInfo<'info>,
+    to_account: AccountInfo<'info>,
+    signer: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    amount: u64,
+    decimal_amount: u8
+) -> Result<u64> {
+    let payer_address = signer.key();
+    let treasury_vault_address = to_account.key();
+    let fixed_pointed_notation_amount = convert_cents_to_token_amount(amount, decimal_amount)?;
+    let transfer_amount = add_transfer_fee(&mint, fixed_pointed_notation_amount)?;
 
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_ata.to_account_info(),
-            accounts.treasurer_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS,
-            accounts.fee_token_entry.decimal_amount
-        )?;
+    let cpi_accounts = token_interface::TransferChecked {
+        from: from_account,
+        mint,
+        to: to_account,
+        authority: signer
+    };
+    let cpi_program = token_program;
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-        Ok(())
-    }
+    //Transfer fee to Treasury Vault
+    token_interface::transfer_checked(cpi_ctx, transfer_amount, decimal_amount)?;
 
-    pub fn post_m4a_comment(ctx: Context<PostM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    emit!(FeeCharged
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-
-        m4a_comment.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        m4a_chat.comment_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.comment_count += 1;
-        comment_section.comment_and_reply_count += 1;
-
-        m4a_comment.id = comment_section.comment_and_reply_count;
-        m4a_comment.protocol_post_count = chat_protocol.comment_and_reply_count;
-        m4a_comment.comment_section_name_prefix = comment_section_name_prefix.clone();
-        m4a_comment.comment_section_name = comment_section_name.clone();
-        m4a_comment.post_owner_address = ctx.accounts.signer.key();
-        m4a_comment.msg = msg.clone();
-        m4a_comment.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        msg!("New M4A Comment Posted");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Comment: {}", m4a_comment.msg);
+        payer_address,
+        recipient_address: treasury_vault_address,
+        amount_in_cents: amount,
+        token_amount: fixed_pointed_notation_amount
+    });
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+    msg!("Successfully transferred ${:.2} as fee to treasury vault: {}", amount as f64 / 100.0, treasury_vault_address);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_DOLLAR_TREE,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+    //Return the amount the vault received so the caller can add it to the revenue ledger
+    Ok(fixed_pointed_notation_amount)
+}
 
-        Ok(())
-    }
+//Helper function to refund part of a vote fee from the treasury vault to the voter, capped at what the vault still holds
+fn refund_fee(accounts: &SetPostVote, vault_bump: u8, amount: u64) -> Result<u64> {
+    let decimal_amount = accounts.fee_token_entry.decimal_amount;
+    let fixed_pointed_notation_amount = convert_cents_to_token_amount(amount, decimal_amount)?;
+    //Add the mint's transfer fee on top so the user receives the full refund, capped at what the vault holds
+    let refund_amount = add_transfer_fee(&accounts.token_mint.to_account_info(), fixed_pointed_notation_amount)?.min(accounts.treasury_vault_token_account.amount);
 
-    pub fn reply_to_m4a_comment(ctx: Context<ReplyToM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    if refund_amount == 0
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        let m4a_reply= &mut ctx.accounts.m4a_reply;
+        return Ok(0);
+    }
 
-        m4a_comment.reply_count += 1;
-        m4a_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        m4a_chat.reply_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_count += 1;
-        comment_section.comment_and_reply_count += 1;
+    let token_mint_address = accounts.token_mint.key();
+    let vault_bump = [vault_bump];
+    let vault_seeds: &[&[u8]] = &[b"treasuryVault".as_ref(), token_mint_address.as_ref(), &vault_bump];
+    let signer_seeds = &[vault_seeds];
 
-        m4a_reply.id = comment_section.comment_and_reply_count;
-        m4a_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        m4a_reply.parent_id = m4a_comment.id;
-        m4a_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        m4a_reply.comment_section_name = comment_section_name.clone();
-        m4a_reply.post_owner_address = ctx.accounts.signer.key();
-        m4a_reply.msg = msg.clone();
-        m4a_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        msg!("New M4A Chat Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", m4a_reply.msg);
+    let cpi_accounts = token_interface::TransferChecked {
+        from: accounts.treasury_vault_token_account.to_account_info(),
+        mint: accounts.token_mint.to_account_info(),
+        to: accounts.user_ata.to_account_info(),
+        authority: accounts.treasury_vault.to_account_info()
+    };
+    let cpi_ctx = CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, signer_seeds);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+    //Transfer the refund from the Treasury Vault back to the user
+    token_interface::transfer_checked(cpi_ctx, refund_amount, decimal_amount)?;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+    msg!("Successfully refunded {} native token amount from treasury vault", refund_amount);
 
-        Ok(())
-    }
+    //Return the amount the vault paid out so the caller can take it off the revenue ledger
+    Ok(refund_amount)
+}
 
-    pub fn reply_to_m4a_reply(ctx: Context<ReplyToM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+//Helper function to handle the Stable Coin fee transfer to user receiveing up votes
+fn send_turd_of_tree<'info>(
+    from_account: AccountInfo<'info>,
+    to_account: AccountInfo<'info>,
+    signer: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    tip: AuthorTip
+) -> Result<()> {
+    let AuthorTip { post_owner_address, amount, decimal_amount } = tip;
+    let payer_address = signer.key();
+    let fixed_pointed_notation_amount = convert_cents_to_token_amount(amount, decimal_amount)?;
+    let transfer_amount = add_transfer_fee(&mint, fixed_pointed_notation_amount)?;
+
+    let cpi_accounts = token_interface::TransferChecked {
+        from: from_account,
+        mint,
+        to: to_account,
+        authority: signer
+    };
+    let cpi_program = token_program;
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+    //Transfer fee to Post Owner Wallet
+    token_interface::transfer_checked(cpi_ctx, transfer_amount, decimal_amount)?;
+
+    emit!(FeeCharged
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
+        payer_address,
+        recipient_address: post_owner_address,
+        amount_in_cents: amount,
+        token_amount: fixed_pointed_notation_amount
+    });
 
-        m4a_reply.reply_count += 1;
-        m4a_lv3_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        m4a_chat.reply_lv3_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv3_count += 1;
-        comment_section.comment_and_reply_count += 1;
+    msg!("Successfully transferred ${:.2} as fee to: {}", amount as f64 / 100.0, post_owner_address.key());
 
-        m4a_lv3_reply.id = comment_section.comment_and_reply_count;
-        m4a_lv3_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        m4a_lv3_reply.parent_id = m4a_reply.id;
-        m4a_lv3_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        m4a_lv3_reply.comment_section_name = comment_section_name.clone();
-        m4a_lv3_reply.post_owner_address = ctx.accounts.signer.key();
-        m4a_lv3_reply.msg = msg.clone();
-        m4a_lv3_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        
-        msg!("New M4A Chat Lv3 Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+    Ok(())
+}
+
+//Functions
+#[program]
+pub mod chat
+{
+    use super::*;
+
+    pub fn initialize_chat_protocol_admin_accounts(ctx: Context<InitializeAdminAccounts>) -> Result<()> 
+    {
+        //Only the initial CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), INITIAL_CEO_ADDRESS, AuthorizationError::NotCEO);
 
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        ceo.address = INITIAL_CEO_ADDRESS;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        let treasurer = &mut ctx.accounts.treasurer;
+        treasurer.address = INITIAL_TREASURER_ADDRESS;
+
+        msg!("Chat Protocol Admin Accounts Initialized");
+        msg!("New CEO Address: {}", ceo.address.key());
+        msg!("New Treasurer Address: {}", treasurer.address.key());
 
         Ok(())
     }
 
-    pub fn reply_to_m4a_lv3_reply(ctx: Context<ReplyToM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn propose_chat_protocol_ceo(ctx: Context<PassOnChatProtocolCEO>, new_ceo_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        m4a_lv3_reply.reply_count += 1;
-        m4a_lv4_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        m4a_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        ceo.pending_address = new_ceo_address.key();
 
-        m4a_lv4_reply.id = comment_section.comment_and_reply_count;
-        m4a_lv4_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        m4a_lv4_reply.parent_id = m4a_lv3_reply.id;
-        m4a_lv4_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        m4a_lv4_reply.comment_section_name = comment_section_name.clone();
-        m4a_lv4_reply.post_owner_address = ctx.accounts.signer.key();
-        m4a_lv4_reply.msg = msg.clone();
-        m4a_lv4_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("The Chat Protocol CEO has proposed passing on the title to a new CEO");
+        msg!("Pending CEO: {}", new_ceo_address.key());
 
-        msg!("New M4A Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        Ok(())
+    }
 
+    pub fn accept_chat_protocol_ceo(ctx: Context<PassOnChatProtocolCEO>) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        //There has to be a pending CEO to accept the title
+        require_keys_neq!(ceo.pending_address, Pubkey::default(), InvalidOperationError::NoPendingHandover);
+
+        //Only the pending CEO can accept the title
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.pending_address.key(), AuthorizationError::NotPendingCEO);
+
+        msg!("The Chat Protocol CEO title has been passed on to a new CEO");
+        msg!("New CEO: {}", ceo.pending_address.key());
+
+        emit!(AdminTransferred
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            role: AdminRole::CEO,
+            previous_address: ceo.address,
+            new_address: ceo.pending_address
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        ceo.address = ceo.pending_address;
+        ceo.pending_address = Pubkey::default();
 
         Ok(())
     }
 
-    pub fn reply_to_m4a_lv4_reply(ctx: Context<ReplyToM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn cancel_chat_protocol_ceo_proposal(ctx: Context<PassOnChatProtocolCEO>) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        let m4a_lv4_plus_reply = &mut ctx.accounts.m4a_lv4_plus_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        m4a_lv4_reply.reply_count += 1;
-        m4a_lv4_plus_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        m4a_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        //There has to be a pending CEO to cancel
+        require_keys_neq!(ceo.pending_address, Pubkey::default(), InvalidOperationError::NoPendingHandover);
 
-        m4a_lv4_plus_reply.id = comment_section.comment_and_reply_count;
-        m4a_lv4_plus_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        m4a_lv4_plus_reply.parent_id = m4a_lv4_reply.id;
-        m4a_lv4_plus_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        m4a_lv4_plus_reply.comment_section_name = comment_section_name.clone();
-        m4a_lv4_plus_reply.post_owner_address = ctx.accounts.signer.key();
-        m4a_lv4_plus_reply.msg = msg.clone();
-        m4a_lv4_plus_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("The Chat Protocol CEO has canceled passing on the title");
+        msg!("Canceled Pending CEO: {}", ceo.pending_address.key());
 
-        msg!("New M4A Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        ceo.pending_address = Pubkey::default();
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        Ok(())
+    }
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+    pub fn propose_chat_protocol_treasurer(ctx: Context<PassOnChatProtocolTreasurer>, new_treasurer_address: Pubkey) -> Result<()> 
+    {
+        let treasurer = &mut ctx.accounts.treasurer;
+        //Only the Treasurer can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
+
+        treasurer.pending_address = new_treasurer_address.key();
+
+        msg!("The Chat Protocol Treasurer has proposed passing on the title to a new Treasurer");
+        msg!("Pending Treasurer: {}", new_treasurer_address.key());
 
         Ok(())
     }
 
-    pub fn post_pli_comment(ctx: Context<PostPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn accept_chat_protocol_treasurer(ctx: Context<PassOnChatProtocolTreasurer>) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        
-        pli_comment.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        pli_chat.comment_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.comment_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let treasurer = &mut ctx.accounts.treasurer;
+        //There has to be a pending Treasurer to accept the title
+        require_keys_neq!(treasurer.pending_address, Pubkey::default(), InvalidOperationError::NoPendingHandover);
 
-        pli_comment.id = comment_section.comment_and_reply_count;
-        pli_comment.protocol_post_count = chat_protocol.comment_and_reply_count;
-        pli_comment.comment_section_name_prefix = comment_section_name_prefix.clone();
-        pli_comment.comment_section_name = comment_section_name.clone();
-        pli_comment.post_owner_address = ctx.accounts.signer.key();
-        pli_comment.msg = msg.clone();
-        pli_comment.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        //Only the pending Treasurer can accept the title
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.pending_address.key(), AuthorizationError::NotPendingTreasurer);
 
-        msg!("New PLI Comment Posted");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Comment: {}", pli_comment.msg);
+        msg!("The Chat Protocol Treasurer title has been passed on to a new Treasurer");
+        msg!("New Treasurer: {}", treasurer.pending_address.key());
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        emit!(AdminTransferred
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            role: AdminRole::Treasurer,
+            previous_address: treasurer.address,
+            new_address: treasurer.pending_address
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_DOLLAR_TREE,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        treasurer.address = treasurer.pending_address;
+        treasurer.pending_address = Pubkey::default();
 
         Ok(())
     }
 
-    pub fn reply_to_pli_comment(ctx: Context<ReplyToPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn cancel_chat_protocol_treasurer_proposal(ctx: Context<PassOnChatProtocolTreasurer>) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        let pli_reply= &mut ctx.accounts.pli_reply;
-
-        pli_comment.reply_count += 1;
-        pli_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        pli_chat.reply_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_count += 1;
-        comment_section.comment_and_reply_count += 1;
-
-        pli_reply.id = comment_section.comment_and_reply_count;
-        pli_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        pli_reply.parent_id = pli_comment.id;
-        pli_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        pli_reply.comment_section_name = comment_section_name.clone();
-        pli_reply.post_owner_address = ctx.accounts.signer.key();
-        pli_reply.msg = msg.clone();
-        pli_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        let treasurer = &mut ctx.accounts.treasurer;
+        //Only the Treasurer can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
 
-        msg!("New PLI Chat Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", pli_reply.msg);
+        //There has to be a pending Treasurer to cancel
+        require_keys_neq!(treasurer.pending_address, Pubkey::default(), InvalidOperationError::NoPendingHandover);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("The Chat Protocol Treasurer has canceled passing on the title");
+        msg!("Canceled Pending Treasurer: {}", treasurer.pending_address.key());
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        treasurer.pending_address = Pubkey::default();
 
         Ok(())
     }
 
-    pub fn reply_to_pli_reply(ctx: Context<ReplyToPLIReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn add_fee_token_entry(ctx: Context<AddFeeTokenEntry>, token_mint_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        pli_reply.reply_count += 1;
-        pli_lv3_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        pli_chat.reply_lv3_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv3_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        //Read the decimals from the mint itself instead of trusting the caller
+        let decimal_amount = ctx.accounts.token_mint.decimals;
 
-        pli_lv3_reply.id = comment_section.comment_and_reply_count;
-        pli_lv3_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        pli_lv3_reply.parent_id = pli_reply.id;
-        pli_lv3_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        pli_lv3_reply.comment_section_name = comment_section_name.clone();
-        pli_lv3_reply.post_owner_address = ctx.accounts.signer.key();
-        pli_lv3_reply.msg = msg.clone();
-        pli_lv3_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        //Fees are in cents so the token needs atleast 2 decimals
+        require!(decimal_amount >= MIN_FEE_TOKEN_DECIMAL_AMOUNT, InvalidOperationError::UnsupportedFeeTokenDecimals);
 
-        msg!("New PLI Chat Lv3 Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        let fee_token_entry = &mut ctx.accounts.fee_token_entry;
+        fee_token_entry.token_mint_address = token_mint_address;
+        fee_token_entry.decimal_amount = decimal_amount;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("Added Fee Token Entry");
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Decimal Amount: {}", decimal_amount);
+            
+        Ok(())
+    }
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+    pub fn remove_fee_token_entry(ctx: Context<RemoveFeeTokenEntry>, token_mint_address: Pubkey) -> Result<()> 
+    {
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
+        msg!("Removed Fee Token Entry");
+        msg!("Mint Address: {}", token_mint_address.key());
+            
         Ok(())
     }
 
-    pub fn reply_to_pli_lv3_reply(ctx: Context<ReplyToPLILv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn create_treasury_vault(ctx: Context<CreateTreasuryVault>, token_mint_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        let pli_lv4_reply= &mut ctx.accounts.pli_lv4_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        pli_lv3_reply.reply_count += 1;
-        pli_lv4_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        pli_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let treasury_vault = &mut ctx.accounts.treasury_vault;
+        treasury_vault.token_mint_address = token_mint_address;
 
-        pli_lv4_reply.id = comment_section.comment_and_reply_count;
-        pli_lv4_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        pli_lv4_reply.parent_id = pli_lv3_reply.id;
-        pli_lv4_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        pli_lv4_reply.comment_section_name = comment_section_name.clone();
-        pli_lv4_reply.post_owner_address = ctx.accounts.signer.key();
-        pli_lv4_reply.msg = msg.clone();
-        pli_lv4_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("Created Treasury Vault");
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Vault Token Account: {}", ctx.accounts.treasury_vault_token_account.key());
 
-        msg!("New PLI Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        Ok(())
+    }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, token_mint_address: Pubkey, amount: u64) -> Result<()> 
+    {
+        let treasurer = &mut ctx.accounts.treasurer;
+        //Only the Treasurer can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), treasurer.address.key(), AuthorizationError::NotTreasurer);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        //Add the mint's transfer fee on top so the Treasurer receives the full amount
+        let transfer_amount = add_transfer_fee(&ctx.accounts.token_mint.to_account_info(), amount)?;
+
+        let vault_bump = [ctx.bumps.treasury_vault];
+        let vault_seeds: &[&[u8]] = &[b"treasuryVault".as_ref(), token_mint_address.as_ref(), &vault_bump];
+        let signer_seeds = &[vault_seeds];
+
+        let cpi_accounts = token_interface::TransferChecked {
+            from: ctx.accounts.treasury_vault_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            to: ctx.accounts.treasurer_ata.to_account_info(),
+            authority: ctx.accounts.treasury_vault.to_account_info()
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer_seeds);
+
+        //Transfer from the Treasury Vault to the Treasurer Wallet
+        token_interface::transfer_checked(cpi_ctx, transfer_amount, ctx.accounts.token_mint.decimals)?;
+
+        let treasury_vault = &mut ctx.accounts.treasury_vault;
+        treasury_vault.withdrawn_amount += transfer_amount as u128;
+
+        msg!("Treasurer Withdrew From Treasury Vault");
+        msg!("Treasurer: {}", ctx.accounts.signer.key());
+        msg!("Mint Address: {}", token_mint_address.key());
+        msg!("Amount: {}", amount);
 
         Ok(())
     }
 
-    pub fn reply_to_pli_lv4_reply(ctx: Context<ReplyToPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn grant_moderator(ctx: Context<GrantModerator>, moderator_address: Pubkey, permissions: u8) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        let pli_lv4_plus_reply = &mut ctx.accounts.pli_lv4_plus_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        pli_lv4_reply.reply_count += 1;
-        pli_lv4_plus_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        pli_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        //Permissions must be non 0 and can only use known bits
+        require!(permissions != 0 && permissions & !ALL_MODERATOR_PERMISSIONS == 0, InvalidOperationError::InvalidModeratorPermissions);
 
-        pli_lv4_plus_reply.id = comment_section.comment_and_reply_count;
-        pli_lv4_plus_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        pli_lv4_plus_reply.parent_id = pli_lv4_reply.id;
-        pli_lv4_plus_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        pli_lv4_plus_reply.comment_section_name = comment_section_name.clone();
-        pli_lv4_plus_reply.post_owner_address = ctx.accounts.signer.key();
-        pli_lv4_plus_reply.msg = msg.clone();
-        pli_lv4_plus_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        let moderator = &mut ctx.accounts.moderator;
+        moderator.address = moderator_address;
+        moderator.permissions = permissions;
+        moderator.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        msg!("New PLI Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        msg!("Moderator Granted By CEO");
+        msg!("Moderator: {}", moderator_address.key());
+        msg!("Permissions: {}", permissions);
+
+        Ok(())
+    }
 
+    pub fn revoke_moderator(ctx: Context<RevokeModerator>, moderator_address: Pubkey) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        msg!("Moderator Revoked By CEO");
+        msg!("Moderator: {}", moderator_address.key());
 
         Ok(())
     }
 
-    pub fn post_about_comment(ctx: Context<PostAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn initialize_quality_of_life_accounts(ctx: Context<InitializeQualityOfLifeAccounts>) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_comment = &mut ctx.accounts.about_comment;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        about_comment.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        about_chat.comment_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.comment_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let fee_schedule = &mut ctx.accounts.fee_schedule;
+        fee_schedule.comment_fee = DEFAULT_COMMENT_FEE;
+        fee_schedule.reply_fee = DEFAULT_REPLY_FEE;
+        fee_schedule.edit_fee = DEFAULT_EDIT_FEE;
+        fee_schedule.delete_fee = DEFAULT_DELETE_FEE;
+        fee_schedule.up_vote_fee = DEFAULT_UP_VOTE_FEE;
+        fee_schedule.down_vote_fee = DEFAULT_DOWN_VOTE_FEE;
+        fee_schedule.author_tip_share = DEFAULT_AUTHOR_TIP_SHARE;
+        fee_schedule.name_change_fee = DEFAULT_NAME_CHANGE_FEE;
+        fee_schedule.name_flag_toggle_fee = DEFAULT_NAME_FLAG_TOGGLE_FEE;
+        fee_schedule.video_vote_fee = DEFAULT_VIDEO_VOTE_FEE;
+        fee_schedule.poll_vote_fee = DEFAULT_POLL_VOTE_FEE;
+
+        let reputation_config = &mut ctx.accounts.reputation_config;
+        reputation_config.up_vote_weight = DEFAULT_UP_VOTE_REPUTATION_WEIGHT;
+        reputation_config.down_vote_weight = DEFAULT_DOWN_VOTE_REPUTATION_WEIGHT;
+        reputation_config.star_weight = DEFAULT_STAR_REPUTATION_WEIGHT;
+        reputation_config.fed_weight = DEFAULT_FED_REPUTATION_WEIGHT;
+        reputation_config.decay_half_life_seconds = DEFAULT_REPUTATION_HALF_LIFE_SECONDS;
 
-        about_comment.id = comment_section.comment_and_reply_count;
-        about_comment.protocol_post_count = chat_protocol.comment_and_reply_count;
-        about_comment.comment_section_name_prefix = comment_section_name_prefix.clone();
-        about_comment.comment_section_name = comment_section_name.clone();
-        about_comment.post_owner_address = ctx.accounts.signer.key();
-        about_comment.msg = msg.clone();
-        about_comment.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("Quality of life accounts initialized");
 
-        msg!("New About Comment Posted");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Comment: {}", about_comment.msg);
+        Ok(())
+    }
 
+    pub fn update_fee_schedule(ctx: Context<UpdateFeeSchedule>, fee_schedule_params: FeeScheduleParams) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_DOLLAR_TREE,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        let fee_schedule = &mut ctx.accounts.fee_schedule;
+        fee_schedule.comment_fee = fee_schedule_params.comment_fee;
+        fee_schedule.reply_fee = fee_schedule_params.reply_fee;
+        fee_schedule.edit_fee = fee_schedule_params.edit_fee;
+        fee_schedule.delete_fee = fee_schedule_params.delete_fee;
+        fee_schedule.up_vote_fee = fee_schedule_params.up_vote_fee;
+        fee_schedule.down_vote_fee = fee_schedule_params.down_vote_fee;
+        fee_schedule.author_tip_share = fee_schedule_params.author_tip_share;
+        fee_schedule.name_change_fee = fee_schedule_params.name_change_fee;
+        fee_schedule.name_flag_toggle_fee = fee_schedule_params.name_flag_toggle_fee;
+        fee_schedule.video_vote_fee = fee_schedule_params.video_vote_fee;
+        fee_schedule.poll_vote_fee = fee_schedule_params.poll_vote_fee;
+
+        msg!("Fee Schedule Updated (in cents)");
+        msg!("Comment: {}, Reply: {}, Edit: {}, Delete: {}", fee_schedule.comment_fee, fee_schedule.reply_fee, fee_schedule.edit_fee, fee_schedule.delete_fee);
+        msg!("Up Vote: {}, Down Vote: {}, Author Tip Share: {}", fee_schedule.up_vote_fee, fee_schedule.down_vote_fee, fee_schedule.author_tip_share);
+        msg!("Name Change: {}, Name Flag Toggle: {}", fee_schedule.name_change_fee, fee_schedule.name_flag_toggle_fee);
+        msg!("Video Vote: {}, Poll Vote: {}", fee_schedule.video_vote_fee, fee_schedule.poll_vote_fee);
 
         Ok(())
     }
 
-    pub fn reply_to_about_comment(ctx: Context<ReplyToAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn set_vote_fee_refund_policy(ctx: Context<UpdateFeeSchedule>, refund_vote_fees_on_change: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_comment = &mut ctx.accounts.about_comment;
-        let about_reply= &mut ctx.accounts.about_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        about_comment.reply_count += 1;
-        about_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        about_chat.reply_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let fee_schedule = &mut ctx.accounts.fee_schedule;
+        fee_schedule.refund_vote_fees_on_change = refund_vote_fees_on_change;
 
-        about_reply.id = comment_section.comment_and_reply_count;
-        about_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        about_reply.parent_id = about_comment.id;
-        about_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        about_reply.comment_section_name = comment_section_name.clone();
-        about_reply.post_owner_address = ctx.accounts.signer.key();
-        about_reply.msg = msg.clone();
-        about_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("Refund Vote Fees On Change Updated To: {}", refund_vote_fees_on_change);
 
-        msg!("New About Chat Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", about_reply.msg);
+        Ok(())
+    }
 
+    pub fn update_reputation_config(ctx: Context<UpdateReputationConfig>,
+        up_vote_weight: i64,
+        down_vote_weight: i64,
+        star_weight: i64,
+        fed_weight: i64,
+        decay_half_life_seconds: u64) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        let reputation_config = &mut ctx.accounts.reputation_config;
+        reputation_config.up_vote_weight = up_vote_weight;
+        reputation_config.down_vote_weight = down_vote_weight;
+        reputation_config.star_weight = star_weight;
+        reputation_config.fed_weight = fed_weight;
+        reputation_config.decay_half_life_seconds = decay_half_life_seconds;
+
+        msg!("Reputation Config Updated");
+        msg!("Up Vote: {}, Down Vote: {}, Star: {}, FED: {}", up_vote_weight, down_vote_weight, star_weight, fed_weight);
+        msg!("Decay Half Life Seconds: {}", decay_half_life_seconds);
 
         Ok(())
     }
 
-    pub fn reply_to_about_reply(ctx: Context<ReplyToAboutReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn initialize_chat_protocol(ctx: Context<InitializeChatProtocol>) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
         let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_reply = &mut ctx.accounts.about_reply;
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-
-        about_reply.reply_count += 1;
-        about_lv3_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        about_chat.reply_lv3_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv3_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        chat_protocol.chat_protocol_initiator_address = ctx.accounts.signer.key();
 
-        about_lv3_reply.id = comment_section.comment_and_reply_count;
-        about_lv3_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        about_lv3_reply.parent_id = about_reply.id;
-        about_lv3_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        about_lv3_reply.comment_section_name = comment_section_name.clone();
-        about_lv3_reply.post_owner_address = ctx.accounts.signer.key();
-        about_lv3_reply.msg = msg.clone();
-        about_lv3_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        //FED records are kept as moderation evidence by default, ideas are cleared with their post
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.keep_fed_record_copy_on_delete = true;
 
-        msg!("New About Chat Lv3 Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        msg!("Chat Protocol Initialized");
+        msg!("Initialized By User: {}", ctx.accounts.signer.key());
+        Ok(())
+    }
 
+    pub fn set_protocol_paused_flags(ctx: Context<UpdateProtocolConfig>, paused_flags: u8) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        //Paused flags can only use known pause bits
+        require!(paused_flags & !ALL_PAUSE_FLAGS == 0, InvalidOperationError::InvalidPausedFlags);
+
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        //Can't set flags to the same state
+        require!(protocol_config.paused_flags != paused_flags, InvalidOperationError::FlagSameState);
+
+        protocol_config.paused_flags = paused_flags;
+
+        msg!("Protocol Paused Flags Updated To: {}", paused_flags);
+        msg!("Posting Paused: {}, Voting Paused: {}", paused_flags & PAUSE_POSTING != 0, paused_flags & PAUSE_VOTING != 0);
+        msg!("Fees Paused: {}, Polls Paused: {}", paused_flags & PAUSE_FEES != 0, paused_flags & PAUSE_POLLS != 0);
 
         Ok(())
     }
 
-    pub fn reply_to_about_lv3_reply(ctx: Context<ReplyToAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn set_deleted_post_copy_policy(ctx: Context<UpdateProtocolConfig>, keep_idea_copy_on_delete: bool, keep_fed_record_copy_on_delete: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        let about_lv4_reply= &mut ctx.accounts.about_lv4_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        about_lv3_reply.reply_count += 1;
-        about_lv4_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        about_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.keep_idea_copy_on_delete = keep_idea_copy_on_delete;
+        protocol_config.keep_fed_record_copy_on_delete = keep_fed_record_copy_on_delete;
 
-        about_lv4_reply.id = comment_section.comment_and_reply_count;
-        about_lv4_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        about_lv4_reply.parent_id = about_lv3_reply.id;
-        about_lv4_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        about_lv4_reply.comment_section_name = comment_section_name.clone();
-        about_lv4_reply.post_owner_address = ctx.accounts.signer.key();
-        about_lv4_reply.msg = msg.clone();
-        about_lv4_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        msg!("Deleted Post Copy Policy Updated");
+        msg!("Keep Idea Copy: {}, Keep FED Record Copy: {}", keep_idea_copy_on_delete, keep_fed_record_copy_on_delete);
 
-        msg!("New About Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        Ok(())
+    }
 
+    pub fn set_edit_window(ctx: Context<UpdateProtocolConfig>, edit_window_seconds: u64) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        let protocol_config = &mut ctx.accounts.protocol_config;
+        protocol_config.edit_window_seconds = edit_window_seconds;
+
+        msg!("Edit Window Updated To: {} Seconds", edit_window_seconds);
 
         Ok(())
     }
 
-    pub fn reply_to_about_lv4_reply(ctx: Context<ReplyToAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn create_chat(ctx: Context<CreateChat>, chat_name: String, max_post_depth: u8) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        let about_lv4_plus_reply = &mut ctx.accounts.about_lv4_plus_reply;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        about_lv4_reply.reply_count += 1;
-        about_lv4_plus_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        about_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        //Chat name string must not be longer than 32 characters
+        require!(chat_name.len() <= MAX_CHAT_NAME_LENGTH, InvalidLengthError::ChatNameTooLong);
 
-        about_lv4_plus_reply.id = comment_section.comment_and_reply_count;
-        about_lv4_plus_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        about_lv4_plus_reply.parent_id = about_lv4_reply.id;
-        about_lv4_plus_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        about_lv4_plus_reply.comment_section_name = comment_section_name.clone();
-        about_lv4_plus_reply.post_owner_address = ctx.accounts.signer.key();
-        about_lv4_plus_reply.msg = msg.clone();
-        about_lv4_plus_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        let chat_protocol = &mut ctx.accounts.chat_protocol;
+        let chat = &mut ctx.accounts.chat;
+
+        chat_protocol.chat_count += 1;
+        chat.id = chat_protocol.chat_count;
+        chat.chat_initiator_address = ctx.accounts.signer.key();
+        chat.chat_name = chat_name.clone();
+        chat.max_post_depth = max_post_depth;
+
+        msg!("New Chat Created");
+        msg!("Chat Count: {}", chat_protocol.chat_count);
+        msg!("Chat: {}", chat_name);
+        msg!("Max Post Depth: {}", max_post_depth);
+        msg!("Created By User: {}", ctx.accounts.signer.key());
 
-        msg!("New About Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        Ok(())
+    }
 
+    pub fn set_chat_max_post_depth(ctx: Context<UpdateChatSettings>, chat_name: String, max_post_depth: u8) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        let chat = &mut ctx.accounts.chat;
+        //Can't set max post depth to the same value
+        require!(chat.max_post_depth != max_post_depth, InvalidOperationError::FlagSameState);
+
+        chat.max_post_depth = max_post_depth;
+
+        msg!("Chat: {}", chat_name);
+        msg!("Max Post Depth Updated To: {}", max_post_depth);
 
         Ok(())
     }
 
-    pub fn post_lo_comment(ctx: Context<PostLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn set_chat_vote_mode(ctx: Context<UpdateChatSettings>, chat_name: String, use_changeable_votes: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_comment = &mut ctx.accounts.lo_comment;
+        let ceo = &mut ctx.accounts.ceo;
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-        lo_comment.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        lo_chat.comment_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.comment_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let chat = &mut ctx.accounts.chat;
+        //Can't set flag to the same state
+        require!(chat.use_changeable_votes != use_changeable_votes, InvalidOperationError::FlagSameState);
 
-        lo_comment.id = comment_section.comment_and_reply_count;
-        lo_comment.protocol_post_count = chat_protocol.comment_and_reply_count;
-        lo_comment.comment_section_name_prefix = comment_section_name_prefix.clone();
-        lo_comment.comment_section_name = comment_section_name.clone();
-        lo_comment.post_owner_address = ctx.accounts.signer.key();
-        lo_comment.msg = msg.clone();
-        lo_comment.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        chat.use_changeable_votes = use_changeable_votes;
 
-        msg!("New LO Comment Posted");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Comment: {}", lo_comment.msg);
+        msg!("Chat: {}", chat_name);
+        msg!("Use Changeable Votes Updated To: {}", use_changeable_votes);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        Ok(())
+    }
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_DOLLAR_TREE,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+    pub fn create_chat_account(ctx: Context<CreateChatAccount>) -> Result<()> 
+    {
+        //Can't create chat accounts while posting is paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING), InvalidOperationError::ProtocolPaused);
+
+        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
+        let chat_account = &mut ctx.accounts.chat_account;
+
+        chat_account_stats.chat_account_count += 1;
+        chat_account.id = chat_account_stats.chat_account_count;
+        chat_account.user_address = ctx.accounts.signer.key();
+
+        msg!("Chat Account Created For: {}", ctx.accounts.signer.key());
+        msg!("Chat Account Number: {}", chat_account_stats.chat_account_count);
 
         Ok(())
     }
 
-    pub fn reply_to_lo_comment(ctx: Context<ReplyToLOComment>,
+    pub fn create_comment_section(ctx: Context<CreateCommentSection>,
         comment_section_name_prefix: String,
-        comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        comment_section_name: String) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        let lo_reply= &mut ctx.accounts.lo_reply;
+        //Can't create comment sections while posting is paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING), InvalidOperationError::ProtocolPaused);
 
-        lo_comment.reply_count += 1;
-        lo_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        lo_chat.reply_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        //Comment section prefix name string must not be longer than 32 characters
+        require!(comment_section_name_prefix.len() <= MAX_COMMENT_SECTION_PREFIX_OR_NAME_LENGTH, InvalidLengthError::CommentSectionNamePrefixTooLong);
 
-        lo_reply.id = comment_section.comment_and_reply_count;
-        lo_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        lo_reply.parent_id = lo_comment.id;
-        lo_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        lo_reply.comment_section_name = comment_section_name.clone();
-        lo_reply.post_owner_address = ctx.accounts.signer.key();
-        lo_reply.msg = msg.clone();
-        lo_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        //Comment section name string must not be longer than 32 characters
+        require!(comment_section_name.len() <= MAX_COMMENT_SECTION_PREFIX_OR_NAME_LENGTH, InvalidLengthError::CommentSectionNameTooLong);
 
-        msg!("New LO Chat Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
+        let comment_section = &mut ctx.accounts.comment_section;
+
+        comment_section_stats.comment_section_count += 1;
+        comment_section.id = comment_section_stats.comment_section_count;
+        comment_section.comment_section_initiator_address = ctx.accounts.signer.key();
+        comment_section.owner_address = ctx.accounts.signer.key();
+        comment_section.comment_section_name_prefix = comment_section_name_prefix.clone();
+        comment_section.comment_section_name = comment_section_name.clone();
+
+        msg!("New Comment Section Created");
+        msg!("Comment Section Count: {}", comment_section_stats.comment_section_count);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", lo_reply.msg);
+        msg!("Created By User: {}", ctx.accounts.signer.key());
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        let chat_account = &mut ctx.accounts.chat_account;
+        if !chat_account.has_good_ending
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            chat_account.has_good_ending = true;
         }
-
+       
         Ok(())
     }
 
-    pub fn reply_to_lo_reply(ctx: Context<ReplyToLOReply>,
+    pub fn set_comment_section_flag(ctx: Context<SetCommentSectionFlag>,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        is_enabled: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
+        //Only the CEO, a moderator with the disable section permission or the comment section owner can call this function
+        require!(is_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_DISABLE_SECTION) ||
+            ctx.accounts.signer.key() == ctx.accounts.comment_section.owner_address.key(), AuthorizationError::NotCEOModeratorOrSectionOwner);
+
+        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
         let comment_section = &mut ctx.accounts.comment_section;
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
 
-        lo_reply.reply_count += 1;
-        lo_lv3_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        lo_chat.reply_lv3_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv3_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        comment_section_stats.toggle_flag_count += 1;
 
-        lo_lv3_reply.id = comment_section.comment_and_reply_count;
-        lo_lv3_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        lo_lv3_reply.parent_id = lo_reply.id;
-        lo_lv3_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        lo_lv3_reply.comment_section_name = comment_section_name.clone();
-        lo_lv3_reply.post_owner_address = ctx.accounts.signer.key();
-        lo_lv3_reply.msg = msg.clone();
-        lo_lv3_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        //Can't set flag to the same state
+        require!(comment_section.is_disabled != is_enabled, InvalidOperationError::FlagSameState);
+
+        comment_section.is_disabled = is_enabled;
 
-        msg!("New LO Chat Lv3 Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
+        msg!("Disabled Flag Updated To: {}", is_enabled);
+        
+        Ok(())
+    }
 
+    pub fn set_comment_section_vote_pricing_mode(ctx: Context<UpdateCommentSectionSettings>,
+        comment_section_name_prefix: String,
+        comment_section_name: String,
+        vote_pricing_mode: u8) -> Result<()> 
+    {
         let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //Only the CEO can call this function
+        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        //Vote pricing mode must be a known mode
+        require!(vote_pricing_mode == VOTE_PRICING_LINEAR || vote_pricing_mode == VOTE_PRICING_QUADRATIC, InvalidOperationError::InvalidVotePricingMode);
+
+        let comment_section = &mut ctx.accounts.comment_section;
+        //Can't set mode to the same state
+        require!(comment_section.vote_pricing_mode != vote_pricing_mode, InvalidOperationError::FlagSameState);
+
+        comment_section.vote_pricing_mode = vote_pricing_mode;
+
+        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+        msg!("Comment Section: {}", comment_section_name);
+        msg!("Vote Pricing Mode Updated To: {}", vote_pricing_mode);
 
         Ok(())
     }
 
-    pub fn reply_to_lo_lv3_reply(ctx: Context<ReplyToLOLv3Reply>,
+    pub fn appoint_section_moderator(ctx: Context<AppointSectionModerator>,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        moderator_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        let lo_lv4_reply= &mut ctx.accounts.lo_lv4_reply;
+        //Only the comment section owner or the CEO can call this function
+        require!(is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section), AuthorizationError::NotSectionOwner);
 
-        lo_lv3_reply.reply_count += 1;
-        lo_lv4_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        lo_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
+        let comment_section = &mut ctx.accounts.comment_section;
+        let section_moderator = &mut ctx.accounts.section_moderator;
 
-        lo_lv4_reply.id = comment_section.comment_and_reply_count;
-        lo_lv4_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        lo_lv4_reply.parent_id = lo_lv3_reply.id;
-        lo_lv4_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        lo_lv4_reply.comment_section_name = comment_section_name.clone();
-        lo_lv4_reply.post_owner_address = ctx.accounts.signer.key();
-        lo_lv4_reply.msg = msg.clone();
-        lo_lv4_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        comment_section.section_moderator_count += 1;
+        section_moderator.address = moderator_address;
+        section_moderator.comment_section_name_prefix = comment_section_name_prefix.clone();
+        section_moderator.comment_section_name = comment_section_name.clone();
+        section_moderator.appointed_by_address = ctx.accounts.signer.key();
+        section_moderator.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        msg!("New LO Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Section Moderator Appointed");
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        msg!("Moderator: {}", moderator_address.key());
 
         Ok(())
     }
 
-    pub fn reply_to_lo_lv4_reply(ctx: Context<ReplyToLOLv4Reply>,
+    pub fn remove_section_moderator(ctx: Context<RemoveSectionModerator>,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        moderator_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-    
-        let chat_protocol = &mut ctx.accounts.chat_protocol;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        let lo_lv4_plus_reply = &mut ctx.accounts.lo_lv4_plus_reply;
+        //Only the comment section owner or the CEO can call this function
+        require!(is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section), AuthorizationError::NotSectionOwner);
 
-        lo_lv4_reply.reply_count += 1;
-        lo_lv4_plus_reply.chat_account_post_count_index = chat_account.comment_and_reply_count;
-        chat_protocol.comment_and_reply_count += 1;
-        lo_chat.reply_lv4_count += 1;
-        chat_account.comment_and_reply_count += 1;
-        comment_section.reply_lv4_count += 1;
-        comment_section.comment_and_reply_count += 1;
-
-        lo_lv4_plus_reply.id = comment_section.comment_and_reply_count;
-        lo_lv4_plus_reply.protocol_post_count = chat_protocol.comment_and_reply_count;
-        lo_lv4_plus_reply.parent_id = lo_lv4_reply.id;
-        lo_lv4_plus_reply.comment_section_name_prefix = comment_section_name_prefix.clone();
-        lo_lv4_plus_reply.comment_section_name = comment_section_name.clone();
-        lo_lv4_plus_reply.post_owner_address = ctx.accounts.signer.key();
-        lo_lv4_plus_reply.msg = msg.clone();
-        lo_lv4_plus_reply.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        let comment_section = &mut ctx.accounts.comment_section;
+        comment_section.section_moderator_count -= 1;
 
-        msg!("New LO Chat Lv4+ Reply");
-        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Section Moderator Removed");
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Message: {}", msg);
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+        msg!("Moderator: {}", moderator_address.key());
 
         Ok(())
     }
 
-    pub fn edit_m4a_comment(ctx: Context<EditM4AComment>,
+    pub fn transfer_comment_section_ownership(ctx: Context<TransferCommentSectionOwnership>,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        new_owner_address: Pubkey) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Only the comment section owner or the CEO can call this function
+        require!(is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section), AuthorizationError::NotSectionOwner);
 
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
 
-        //You can't edit a comment that has been deleted
-        require!(m4a_comment.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't edit a comment that isn't yours
-        require_keys_eq!(m4a_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.edited_comment_count += 1;
-        comment_section.edited_comment_count += 1;
- 
-        msg!("M4A Comment Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Comment Section Ownership Transferred");
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        m4a_comment.msg = msg;
+        msg!("Previous Owner: {}", comment_section.owner_address.key());
+        msg!("New Owner: {}", new_owner_address.key());
 
-        if m4a_comment.is_edited == false
-        {
-            m4a_comment.is_edited = true; 
-        }
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        comment_section.owner_address = new_owner_address;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_m4a_reply(ctx: Context<EditM4AReply>,
+    pub fn set_post_pinned_flag(ctx: Context<SetPostSectionFlag>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
+        post_owner_address: Pubkey,
         _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        is_pinned: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-  
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
+        //Only a comment section moderator, the comment section owner or the CEO can call this function
+        require!(ctx.accounts.section_moderator.is_some() || is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section), AuthorizationError::NotSectionModerator);
 
-        //You can't edit a reply that has been deleted
-        require!(m4a_reply.is_deleted == false, InvalidOperationError::Deleted);
+        let comment_section = &mut ctx.accounts.comment_section;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(m4a_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.edited_reply_count += 1;
-        comment_section.edited_reply_count += 1;
+        //Can't set flag to the same state because of the counters
+        require!(post.is_pinned != is_pinned, InvalidOperationError::FlagSameState);
 
-        msg!("M4A Reply Edited By User");
+        if is_pinned
+        {
+            comment_section.pinned_post_count += 1;
+        }
+        else
+        {
+            comment_section.pinned_post_count -= 1;
+        }
+
+        post.is_pinned = is_pinned;
+
+        msg!("Post Pinned Flag Updated To: {}", is_pinned);
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        m4a_reply.msg = msg;
 
-        if m4a_reply.is_edited == false
+        Ok(())
+    }
+
+    pub fn set_post_hidden_flag(ctx: Context<SetPostSectionFlag>,
+        chat_name: String,
+        comment_section_name_prefix: String,
+        comment_section_name: String,
+        post_owner_address: Pubkey,
+        _chat_account_post_count_index: u128,
+        is_hidden: bool) -> Result<()> 
+    {
+        //Only a comment section moderator, the comment section owner or the CEO can call this function
+        require!(ctx.accounts.section_moderator.is_some() || is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section), AuthorizationError::NotSectionModerator);
+
+        let comment_section = &mut ctx.accounts.comment_section;
+        let post = &mut ctx.accounts.post;
+
+        //Can't set flag to the same state because of the counters
+        require!(post.is_hidden != is_hidden, InvalidOperationError::FlagSameState);
+
+        if is_hidden
         {
-            m4a_reply.is_edited = true; 
+            comment_section.hidden_post_count += 1;
         }
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        else
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            comment_section.hidden_post_count -= 1;
         }
-     
+
+        post.is_hidden = is_hidden;
+
+        msg!("Post Hidden Flag Updated To: {}", is_hidden);
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
+        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+        msg!("Comment Section: {}", comment_section_name);
+
         Ok(())
     }
 
-    pub fn edit_m4a_lv3_reply(ctx: Context<EditM4ALv3Reply>,
+    //This vote could be for a video, or what ever is on the page of the comment section
+    pub fn comment_section_vote(ctx: Context<CommentSectionVote>,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
         _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        vote_amount: i128) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Can't vote while voting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_VOTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //You can't vote in a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false, InvalidOperationError::CommentSectionDisabled);
+
+        //You can not vote a 0 ammount
+        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
+
+        let mut is_up_vote = false;
+
+        if vote_amount > 0
+        {
+            is_up_vote = true;
+        }
 
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
         let chat_account = &mut ctx.accounts.chat_account;
+        let comment_section_stats = &mut ctx.accounts.comment_section_stats;
         let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-
-        //You can't edit a reply that has been deleted
-        require!(m4a_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        let video_vote_stats = &mut ctx.accounts.video_vote_stats;
+        let video_vote_record = &mut ctx.accounts.video_vote_record;
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(m4a_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
+        if !chat_account.has_good_ending
+        {
+            chat_account.has_good_ending = true;
+        }
+    
+        if is_up_vote
+        {
+            video_vote_stats.video_up_vote_count += 1;
+            comment_section_stats.video_up_vote_count += 1;
+            comment_section.video_up_vote_score += vote_amount as u128;
+            comment_section.video_up_vote_count += 1;
         
-        m4a_chat.edited_lv3_reply_count += 1;
-        comment_section.edited_lv3_reply_count += 1;
+            msg!("User Address: {}", ctx.accounts.signer.key());
+            msg!("Up Voted Comment Section Video");
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Vote Amount: {}", vote_amount);
+        }
+        else
+        {
+            video_vote_stats.video_down_vote_count += 1;
+            comment_section_stats.video_down_vote_count += 1;
+            comment_section.video_down_vote_score += vote_amount.abs() as u128;
+            comment_section.video_down_vote_count += 1;
+            
+            msg!("User Address: {}", ctx.accounts.signer.key());
+            msg!("Down Voted Comment Section Video");
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Vote Amount: {}", vote_amount);
+        }
 
-        msg!("M4A Lv3 Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
         
-        chat_account.edited_comment_and_reply_count += 1;
-        m4a_lv3_reply.msg = msg;
+        video_vote_record.protocol_record_id = comment_section_stats.video_up_vote_count + comment_section_stats.video_down_vote_count;
+        video_vote_record.comment_section_record_id = comment_section.video_up_vote_count + comment_section.video_down_vote_count;
+        video_vote_record.voter_address = ctx.accounts.signer.key();
+        video_vote_record.comment_section_name_prefix = comment_section_name_prefix;
+        video_vote_record.comment_section_name = comment_section_name;
+        
+        video_vote_record.vote_amount = vote_amount;
+        video_vote_record.unix_creation_time_stamp = time_stamp;
+
+        chat_account.video_vote_count += 1;
+
+        //Quadratic pricing charges for the votes this voter already cast on the comment section
+        let vote_cost_units = add_to_vote_tally(&mut ctx.accounts.vote_tally, comment_section.key(), ctx.accounts.signer.key(), comment_section.vote_pricing_mode, vote_amount)?;
+
+        let accounts = &ctx.accounts;
+
+        //Call the helper function to transfer the fee
+        let fee_amount = apply_fee(
+            accounts.user_ata.to_account_info(),
+            accounts.treasury_vault_token_account.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.token_mint.to_account_info(),
+            accounts.fee_schedule.video_vote_fee.checked_mul(vote_cost_units).ok_or(InvalidOperationError::FeeAmountOverflow)?,
+            accounts.fee_token_entry.decimal_amount
+        )?;
+
+        ctx.accounts.treasury_vault.video_vote_revenue += fee_amount as u128;
+
+        Ok(())
+    }
+
+    pub fn update_user_name(ctx: Context<UpdateUserName>, _token_mint_address: Pubkey, user_name: String) -> Result<()> 
+    {
+        //Can't charge fees while fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //User Name string must not be longer than 144 characters
+        require!(user_name.len() <= MAX_CUSTOM_USER_NAME_LENGTH, InvalidLengthError::UserNameTooLong);
+
+        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
+        chat_account_stats.updated_name_count += 1;
+
+        let chat_account = &mut ctx.accounts.chat_account;
+        chat_account.user_name = user_name.clone();
+        chat_account.use_custom_name = true;
 
-        if m4a_lv3_reply.is_edited == false
+        if chat_account.has_had_custom_name == false
         {
-            m4a_lv3_reply.is_edited = true; 
+            chat_account.has_had_custom_name = true
         }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("User Name Updated For: {}", ctx.accounts.signer.key());
+        msg!("User Name: {}", user_name);
+
+        let accounts = &ctx.accounts;
+
+        //Call the helper function to transfer the fee
+        let fee_amount = apply_fee(
+            accounts.user_ata.to_account_info(),
+            accounts.treasury_vault_token_account.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.token_mint.to_account_info(),
+            accounts.fee_schedule.name_change_fee,
+            accounts.fee_token_entry.decimal_amount
+        )?;
+
+        ctx.accounts.treasury_vault.name_change_revenue += fee_amount as u128;
+
+        Ok(())
+    }
+
+    pub fn set_use_custom_name_flag(ctx: Context<SetUseCustomNameFlag>, _token_mint_address: Pubkey, is_enabled: bool, ) -> Result<()> 
+    {
+        //Can't charge fees while fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        let chat_account_stats = &mut ctx.accounts.chat_account_stats;
+        chat_account_stats.set_flag_count += 1;
+
+        let chat_account = &mut ctx.accounts.chat_account;
+        //Can't set flag to the same state
+        require!(chat_account.use_custom_name != is_enabled, InvalidOperationError::FlagSameState);
+
+        chat_account.use_custom_name = is_enabled;
+
+        msg!("User Name Flag Updated For: {}", ctx.accounts.signer.key());
+        msg!("User Name Flag: {}", is_enabled);
+
+        let accounts = &ctx.accounts;
+
+        //Call the helper function to transfer the fee
+        let fee_amount = apply_fee(
+            accounts.user_ata.to_account_info(),
+            accounts.treasury_vault_token_account.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.token_mint.to_account_info(),
+            accounts.fee_schedule.name_flag_toggle_fee,
+            accounts.fee_token_entry.decimal_amount
+        )?;
+
+        ctx.accounts.treasury_vault.name_flag_toggle_revenue += fee_amount as u128;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_m4a_lv4_reply(ctx: Context<EditM4ALv4Reply>,
+    pub fn post_comment(ctx: Context<PostComment>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
         _token_mint_address: Pubkey,
         msg: String) -> Result<()> 
     {
+        //Can't post while posting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //Only the CEO can write to a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false || ctx.accounts.signer.key() == ctx.accounts.ceo.address.key(), InvalidOperationError::CommentSectionDisabled);
+
         //Message string must not be longer than 444 characters
         require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
 
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
+        let chat_protocol = &mut ctx.accounts.chat_protocol;
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that has been deleted
-        require!(m4a_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(m4a_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.edited_lv4_reply_count += 1;
-        comment_section.edited_lv4_reply_count += 1;
+        post.chat_account_post_count_index = chat_account.comment_and_reply_count;
+        chat_protocol.comment_and_reply_count += 1;
+        chat.comment_count += 1;
+        chat_account.comment_and_reply_count += 1;
+        comment_section.comment_count += 1;
+        comment_section.comment_and_reply_count += 1;
 
-        msg!("M4A Lv4+ Reply Edited By User");
+        post.id = comment_section.comment_and_reply_count;
+        post.protocol_post_count = chat_protocol.comment_and_reply_count;
+        post.depth = 0;
+        post.chat_name = chat_name.clone();
+        post.comment_section_name_prefix = comment_section_name_prefix.clone();
+        post.comment_section_name = comment_section_name.clone();
+        post.post_owner_address = ctx.accounts.signer.key();
+        post.msg = msg.clone();
+        post.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        post.hot_score = get_hot_score(0, post.unix_creation_time_stamp);
+
+        msg!("New Comment Posted");
+        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
         msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        m4a_lv4_reply.msg = msg;
+        msg!("Comment: {}", post.msg);
 
-        if m4a_lv4_reply.is_edited == false
+        emit!(PostCreated
         {
-            m4a_lv4_reply.is_edited = true; 
-        }
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address: post.post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            id: post.id,
+            parent_id: post.parent_id,
+            depth: post.depth,
+            msg,
+            unix_creation_time_stamp: post.unix_creation_time_stamp
+        });
 
         let ceo = &mut ctx.accounts.ceo;
         if ctx.accounts.signer.key() != ceo.address.key()
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.comment_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.comment_revenue += fee_amount as u128;
         }
-     
+
         Ok(())
     }
 
-    pub fn edit_pli_comment(ctx: Context<EditPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn reply_to_post(ctx: Context<ReplyToPost>, params: ReplyToPostParams) -> Result<()> 
     {
+        let ReplyToPostParams { chat_name, comment_section_name_prefix, comment_section_name, msg, .. } = params;
+
+        //Can't reply while posting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //Only the CEO can write to a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false || ctx.accounts.signer.key() == ctx.accounts.ceo.address.key(), InvalidOperationError::CommentSectionDisabled);
+
         //Message string must not be longer than 444 characters
         require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
 
-        let pli_chat = &mut ctx.accounts.pli_chat;
+        let chat_protocol = &mut ctx.accounts.chat_protocol;
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let pli_comment = &mut ctx.accounts.pli_comment;
+        let parent_post = &mut ctx.accounts.parent_post;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a comment that has been deleted
-        require!(pli_comment.is_deleted == false, InvalidOperationError::Deleted);
+        //Replies can't be nested deeper than the chat allows
+        require!(parent_post.depth < chat.max_post_depth, InvalidOperationError::MaxPostDepthReached);
 
-        //You can't edit a comment that isn't yours
-        require_keys_eq!(pli_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.edited_comment_count += 1;
-        comment_section.edited_comment_count += 1;
+        parent_post.reply_count += 1;
+        post.chat_account_post_count_index = chat_account.comment_and_reply_count;
+        chat_protocol.comment_and_reply_count += 1;
+        chat.reply_count += 1;
+        chat_account.comment_and_reply_count += 1;
+        comment_section.reply_count += 1;
+        comment_section.comment_and_reply_count += 1;
 
-        msg!("PLI Comment Edited By User");
+        post.id = comment_section.comment_and_reply_count;
+        post.protocol_post_count = chat_protocol.comment_and_reply_count;
+        post.parent_id = parent_post.id;
+        post.parent_post_address = parent_post.key();
+        post.depth = parent_post.depth + 1;
+        post.chat_name = chat_name.clone();
+        post.comment_section_name_prefix = comment_section_name_prefix.clone();
+        post.comment_section_name = comment_section_name.clone();
+        post.post_owner_address = ctx.accounts.signer.key();
+        post.msg = msg.clone();
+        post.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        post.hot_score = get_hot_score(0, post.unix_creation_time_stamp);
+
+        msg!("New Reply Posted");
+        msg!("Chat Protocol Comment And Reply Count: {}", chat_protocol.comment_and_reply_count);
         msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        pli_comment.msg = msg;
+        msg!("Reply Depth: {}", post.depth);
+        msg!("Message: {}", post.msg);
 
-        if pli_comment.is_edited == false
+        emit!(PostCreated
         {
-            pli_comment.is_edited = true; 
-        }
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address: post.post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            id: post.id,
+            parent_id: post.parent_id,
+            depth: post.depth,
+            msg,
+            unix_creation_time_stamp: post.unix_creation_time_stamp
+        });
 
         let ceo = &mut ctx.accounts.ceo;
         if ctx.accounts.signer.key() != ceo.address.key()
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.reply_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.reply_revenue += fee_amount as u128;
         }
-     
+
         Ok(())
     }
 
-    pub fn edit_pli_reply(ctx: Context<EditPLIReply>,
+    pub fn edit_post(ctx: Context<EditPost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
         _chat_account_post_count_index: u128,
         _token_mint_address: Pubkey,
         msg: String) -> Result<()> 
     {
+        //Can't edit while posting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //Only the CEO can write to a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false || ctx.accounts.signer.key() == ctx.accounts.ceo.address.key(), InvalidOperationError::CommentSectionDisabled);
+
         //Message string must not be longer than 444 characters
         require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
 
-        let pli_chat = &mut ctx.accounts.pli_chat;
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let pli_reply = &mut ctx.accounts.pli_reply;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that has been deleted
-        require!(pli_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //You can't edit a post that has been deleted
+        require!(post.is_deleted == false, InvalidOperationError::Deleted);
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(pli_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.edited_reply_count += 1;
-        comment_section.edited_reply_count += 1;
+        //You can't edit a post that isn't yours
+        require_keys_eq!(post.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotPostOwner);
+
+        //You can't edit a post after the CEO or a moderator has starred it
+        require!(post.is_starred == false, InvalidOperationError::StarredPostEditLocked);
+
+        //You can't edit a post after the CEO or a moderator has FED marked it
+        require!(post.is_fed == false, InvalidOperationError::FEDPostEditLocked);
+
+        //You can't edit a post after the edit window has closed
+        let edit_window_seconds = ctx.accounts.protocol_config.edit_window_seconds;
+        require!(edit_window_seconds == 0 || (Clock::get()?.unix_timestamp as u64) <= post.unix_creation_time_stamp.saturating_add(edit_window_seconds), InvalidOperationError::EditWindowClosed);
+
+        if post.depth == 0
+        {
+            chat.edited_comment_count += 1;
+            comment_section.edited_comment_count += 1;
+        }
+        else
+        {
+            chat.edited_reply_count += 1;
+            comment_section.edited_reply_count += 1;
+        }
 
-        msg!("PLI Reply Edited By User");
+        msg!("Post Edited By User");
         msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
         msg!("Edited Message: {}", msg);
-        
+
+        //Keep the previous message in a revision account before it gets overwritten
+        let post_revision = &mut ctx.accounts.post_revision;
+        post_revision.post_address = post.key();
+        post_revision.revision_number = post.revision_count;
+        post_revision.msg = post.msg.clone();
+        post_revision.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        msg!("Revision Number: {}", post.revision_count);
+
         chat_account.edited_comment_and_reply_count += 1;
-        pli_reply.msg = msg;
+        post.revision_count += 1;
+        post.msg = msg.clone();
 
-        if pli_reply.is_edited == false
+        if post.is_edited == false
         {
-            pli_reply.is_edited = true; 
+            post.is_edited = true;
         }
 
+        emit!(PostEdited
+        {
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address: post.post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            msg
+        });
+
         let ceo = &mut ctx.accounts.ceo;
         if ctx.accounts.signer.key() != ceo.address.key()
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.edit_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.edit_revenue += fee_amount as u128;
         }
-     
+
         Ok(())
     }
 
-    pub fn edit_pli_lv3_reply(ctx: Context<EditPLILv3Reply>,
+    /// Clears a post's message and marks it deleted, the account stays open and keeps its rent until the author calls close_post.
+    pub fn delete_post(ctx: Context<DeletePost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
         _chat_account_post_count_index: u128,
         _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        store_content_hash: bool) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Can't delete while posting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //Only the CEO can write to a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false || ctx.accounts.signer.key() == ctx.accounts.ceo.address.key(), InvalidOperationError::CommentSectionDisabled);
 
-        let pli_chat = &mut ctx.accounts.pli_chat;
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that has been deleted
-        require!(pli_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //You can't delete a post that has already been deleted
+        require!(post.is_deleted == false, InvalidOperationError::Deleted);
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(pli_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.edited_lv3_reply_count += 1;
-        comment_section.edited_lv3_reply_count += 1;
+        //You can't delete a post that isn't yours
+        require_keys_eq!(post.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotPostOwner);
 
-        msg!("PLI Lv3 Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        pli_lv3_reply.msg = msg;
-
-        if pli_lv3_reply.is_edited == false
+        if post.depth == 0
         {
-            pli_lv3_reply.is_edited = true; 
+            chat.deleted_comment_count += 1;
+            comment_section.deleted_comment_count += 1;
         }
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        else
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            chat.deleted_reply_count += 1;
+            comment_section.deleted_reply_count += 1;
         }
-     
-        Ok(())
-    }
-
-    pub fn edit_pli_lv4_reply(ctx: Context<EditPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
-    {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-
-        //You can't edit a reply that has been deleted
-        require!(pli_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(pli_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.edited_lv4_reply_count += 1;
-        comment_section.edited_lv4_reply_count += 1;
 
-        msg!("PLI Lv4+ Reply Edited By User");
+        msg!("Post Deleted By User");
         msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        pli_lv4_reply.msg = msg;
+        msg!("Post Depth: {}", post.depth);
 
-        if pli_lv4_reply.is_edited == false
-        {
-            pli_lv4_reply.is_edited = true; 
-        }
+        chat_account.deleted_comment_and_reply_count += 1;
+        post.is_deleted = true;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        //Optionally keep a hash of the message so the deletion can be audited
+        if store_content_hash
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            post.deleted_msg_hash = hash(post.msg.as_bytes()).to_bytes();
         }
-     
-        Ok(())
-    }
-
-    pub fn edit_about_comment(ctx: Context<EditAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
-    {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_comment = &mut ctx.accounts.about_comment;
-
-        //You can't edit a comment that has been deleted
-        require!(about_comment.is_deleted == false, InvalidOperationError::Deleted);
 
-        //You can't edit a comment that isn't yours
-        require_keys_eq!(about_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.edited_comment_count += 1;
-        comment_section.edited_comment_count += 1;
+        post.msg = String::new();
+        update_trending_posts(comment_section, post.key(), post.hot_score, false);
 
-        msg!("About Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        about_comment.msg = msg;
+        clear_deleted_post_copies(&ctx.accounts.protocol_config, post, &mut ctx.accounts.idea, &mut ctx.accounts.fed_record)?;
 
-        if about_comment.is_edited == false
+        emit!(PostDeleted
         {
-            about_comment.is_edited = true; 
-        }
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address: post.post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth
+        });
 
         let ceo = &mut ctx.accounts.ceo;
         if ctx.accounts.signer.key() != ceo.address.key()
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.delete_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.delete_revenue += fee_amount as u128;
         }
-     
+
         Ok(())
     }
 
-    pub fn edit_about_reply(ctx: Context<EditAboutReply>,
+    pub fn moderator_delete_post(ctx: Context<ModeratorDeletePost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
+        post_owner_address: Pubkey,
         _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        removal_reason_code: u8) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Only the CEO, a moderator with the delete posts permission, the comment section owner or a comment section moderator can call this function
+        require!(is_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_DELETE_POSTS) ||
+            is_ceo_or_section_owner(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.comment_section) ||
+            ctx.accounts.section_moderator.is_some(), AuthorizationError::NotCEOOrModerator);
 
-        let about_chat = &mut ctx.accounts.about_chat;
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let about_reply = &mut ctx.accounts.about_reply;
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that has been deleted
-        require!(about_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //You can't delete a post that has already been deleted
+        require!(post.is_deleted == false, InvalidOperationError::Deleted);
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(about_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.edited_reply_count += 1;
-        comment_section.edited_reply_count += 1;
+        if post.depth == 0
+        {
+            chat.deleted_comment_count += 1;
+            comment_section.deleted_comment_count += 1;
+        }
+        else
+        {
+            chat.deleted_reply_count += 1;
+            comment_section.deleted_reply_count += 1;
+        }
 
-        msg!("About Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Post Removed By Moderator");
+        msg!("Moderator Address: {}", ctx.accounts.signer.key());
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        about_reply.msg = msg;
+        msg!("Post Depth: {}", post.depth);
+        msg!("Removal Reason Code: {}", removal_reason_code);
 
-        if about_reply.is_edited == false
-        {
-            about_reply.is_edited = true; 
-        }
+        chat_account.deleted_comment_and_reply_count += 1;
+        post.is_deleted = true;
+        post.deleted_msg_hash = hash(post.msg.as_bytes()).to_bytes();
+        post.msg = String::new();
+        post.removed_by_address = ctx.accounts.signer.key();
+        post.removal_reason_code = removal_reason_code;
+        update_trending_posts(comment_section, post.key(), post.hot_score, false);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        clear_deleted_post_copies(&ctx.accounts.protocol_config, post, &mut ctx.accounts.idea, &mut ctx.accounts.fed_record)?;
+
+        emit!(PostRemoved
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            removed_by_address: ctx.accounts.signer.key(),
+            removal_reason_code
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_about_lv3_reply(ctx: Context<EditAboutLv3Reply>,
+    /// Closes the newest revision of a deleted post and gives its rent back to the author, call it once per revision before close_post.
+    pub fn close_post_revision(ctx: Context<ClosePostRevision>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        _chat_account_post_count_index: u128) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
+        //Can't close posts while posting is paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING), InvalidOperationError::ProtocolPaused);
 
-        //You can't edit a reply that has been deleted
-        require!(about_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        let post = &mut ctx.accounts.post;
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(about_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
+        //The old messages stay on chain for as long as the post isn't deleted
+        require!(post.is_deleted, InvalidOperationError::PostNotDeleted);
 
-        about_chat.edited_lv3_reply_count += 1;
-        comment_section.edited_lv3_reply_count += 1;
+        //Revisions are closed newest first, so the post's revision count is always the number still open
+        post.revision_count -= 1;
 
-        msg!("About Lv3 Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        about_lv3_reply.msg = msg;
-
-        if about_lv3_reply.is_edited == false
-        {
-            about_lv3_reply.is_edited = true; 
-        }
+        msg!("Closed Revision Number: {}", ctx.accounts.post_revision.revision_number);
+        msg!("Remaining Revisions: {}", post.revision_count);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_about_lv4_reply(ctx: Context<EditAboutLv4Reply>,
+    /// Closes a deleted post and gives its rent back to the author, delete_post only clears the post so this is how rent is returned.
+    /// Leaf posts are closed outright, posts that still have replies leave a small tombstone behind so the thread structure survives.
+    pub fn close_post(ctx: Context<ClosePost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        _chat_account_post_count_index: u128) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Can't close posts while posting is paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING), InvalidOperationError::ProtocolPaused);
 
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
+        let post = &ctx.accounts.post;
 
-        //You can't edit a reply that has been deleted
-        require!(about_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //You can only close a post that has been deleted
+        require!(post.is_deleted, InvalidOperationError::PostNotDeleted);
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(about_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.edited_lv4_reply_count += 1;
-        comment_section.edited_lv4_reply_count += 1;
+        //The revisions are found from the post's address, so they have to be closed first
+        require!(post.revision_count == 0, InvalidOperationError::PostHasRevisions);
 
-        msg!("About Lv4+ Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        about_lv4_reply.msg = msg;
+        msg!("Post Depth: {}", post.depth);
 
-        if about_lv4_reply.is_edited == false
-        {
-            about_lv4_reply.is_edited = true; 
-        }
+        //A closed post can't stay on the trending list
+        update_trending_posts(&mut ctx.accounts.comment_section, post.key(), post.hot_score, false);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        //Leaf posts are closed and all of the rent goes back to the author
+        if post.reply_count == 0
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
-        Ok(())
-    }
-
-    pub fn edit_lo_comment(ctx: Context<EditLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
-    {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+            if post.depth > 0
+            {
+                //Replies need their parent post or its tombstone so the parent's reply count stays correct
+                remove_reply_from_parent(&mut ctx.accounts.parent_post, &mut ctx.accounts.parent_post_tombstone)?;
+            }
 
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_comment = &mut ctx.accounts.lo_comment;
+            ctx.accounts.chat_account.closed_comment_and_reply_count += 1;
+            ctx.accounts.post.close(ctx.accounts.signer.to_account_info())?;
 
-        //You can't edit a comment that has been deleted
-        require!(lo_comment.is_deleted == false, InvalidOperationError::Deleted);
+            msg!("Post Closed By User: {}", ctx.accounts.signer.key());
 
-        //You can't edit a comment that isn't yours
-        require_keys_eq!(lo_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.edited_comment_count += 1;
-        comment_section.edited_comment_count += 1;
+            return Ok(());
+        }
 
-        msg!("LO Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        lo_comment.msg = msg;
+        //Posts that still have replies are closed too, but leave a tombstone with only what the thread needs
+        let post_tombstone = ctx.accounts.post_tombstone.as_mut().ok_or(InvalidOperationError::MissingPostTombstone)?;
+        post_tombstone.parent_post_address = post.parent_post_address;
+        post_tombstone.depth = post.depth;
+        post_tombstone.reply_count = post.reply_count;
+        post_tombstone.flags = get_tombstone_flags(post);
 
-        if lo_comment.is_edited == false
-        {
-            lo_comment.is_edited = true; 
-        }
+        ctx.accounts.post.close(ctx.accounts.signer.to_account_info())?;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("Post Tombstoned By User: {}", ctx.accounts.signer.key());
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_lo_reply(ctx: Context<EditLOReply>,
+    /// Closes a tombstone once all of its replies have been closed and gives its rent back to the author of the post it replaced.
+    pub fn close_post_tombstone(ctx: Context<ClosePostTombstone>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+        _chat_account_post_count_index: u128) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        //Can't close posts while posting is paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POSTING), InvalidOperationError::ProtocolPaused);
 
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_reply = &mut ctx.accounts.lo_reply;
+        let post_tombstone = &ctx.accounts.post_tombstone;
 
-        //You can't edit a reply that has been deleted
-        require!(lo_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(lo_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.edited_reply_count += 1;
-        comment_section.edited_reply_count += 1;
+        //The tombstone holds the thread together until its last reply is closed
+        require!(post_tombstone.reply_count == 0, InvalidOperationError::TombstoneHasReplies);
 
-        msg!("LO Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        lo_reply.msg = msg;
+        msg!("Post Depth: {}", post_tombstone.depth);
 
-        if lo_reply.is_edited == false
+        if post_tombstone.depth > 0
         {
-            lo_reply.is_edited = true; 
+            //Replies need their parent post or its tombstone so the parent's reply count stays correct
+            remove_reply_from_parent(&mut ctx.accounts.parent_post, &mut ctx.accounts.parent_post_tombstone)?;
         }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        ctx.accounts.chat_account.closed_comment_and_reply_count += 1;
+
+        msg!("Post Tombstone Closed By User: {}", ctx.accounts.signer.key());
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-     
         Ok(())
     }
 
-    pub fn edit_lo_lv3_reply(ctx: Context<EditLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
+    pub fn post_vote(ctx: Context<PostVote>, params: PostVoteParams) -> Result<()> 
     {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        let PostVoteParams { chat_name, comment_section_name_prefix, comment_section_name, canidate_address, vote_amount, .. } = params;
 
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
+        //Can't vote while voting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_VOTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //You can't vote in a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false, InvalidOperationError::CommentSectionDisabled);
+
+        //Chats with changeable votes only take votes through set_post_vote
+        require!(ctx.accounts.chat.use_changeable_votes == false, InvalidOperationError::ChangeableVotesOnly);
+
+        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
+        let chat = &mut ctx.accounts.chat;
+        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
+        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
+        let post = &mut ctx.accounts.post;
+        let post_vote_record = &mut ctx.accounts.post_vote_record;
 
-        //You can't edit a reply that has been deleted
-        require!(lo_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //You can't vote for a post that has been deleted
+        require!(post.is_deleted == false, InvalidOperationError::Deleted);
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(lo_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.edited_lv3_reply_count += 1;
-        comment_section.edited_lv3_reply_count += 1;
+        //You must vote for the person who wrote the post
+        require_keys_eq!(post.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
 
-        msg!("LO Lv3 Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        lo_lv3_reply.msg = msg;
+        //You can not vote a 0 ammount
+        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
 
-        if lo_lv3_reply.is_edited == false
+        let mut is_up_vote = false;
+
+        if vote_amount > 0
         {
-            lo_lv3_reply.is_edited = true; 
+            is_up_vote = true;
         }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        post_vote_record.voter_address = ctx.accounts.signer.key();
+        post_vote_record.canidate_address = canidate_address.key();
+        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
+        post_vote_record.vote_amount = vote_amount;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+        //Add code to account for voter voting for their own post since can't duplicate accounts
+        if post.post_owner_address.key() == ctx.accounts.signer.key()
+        {
+            if is_up_vote
+            {
+                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
+                voter_chat_account.up_vote_received_count += 1;
+            }
+            else
+            {
+                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
+                voter_chat_account.down_vote_received_count += 1;
+            }
         }
-     
-        Ok(())
-    }
 
-    pub fn edit_lo_lv4_reply(ctx: Context<EditLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        msg: String) -> Result<()> 
-    {
-        //Message string must not be longer than 444 characters
-        require!(msg.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
+        if is_up_vote
+        {
+            post_vote_stats.post_up_vote_count += 1;
+            comment_section.post_up_vote_score += vote_amount as u128;
+            comment_section.post_up_vote_count += 1;
 
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
+            if post.depth == 0
+            {
+                chat.comment_up_vote_count += 1;
+                comment_section.comment_up_vote_score += vote_amount as u128;
+                comment_section.comment_up_vote_count += 1;
+            }
+            else
+            {
+                chat.reply_up_vote_count += 1;
+                comment_section.reply_up_vote_score += vote_amount as u128;
+                comment_section.reply_up_vote_count += 1;
+            }
 
-        //You can't edit a reply that has been deleted
-        require!(lo_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
+            voter_chat_account.casted_up_vote_score += vote_amount as u128;
+            voter_chat_account.up_vote_casted_count += 1;
+            canidate_chat_account.received_up_vote_score += vote_amount as u128;
+            canidate_chat_account.up_vote_received_count += 1;
 
-        //You can't edit a reply that isn't yours
-        require_keys_eq!(lo_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.edited_lv4_reply_count += 1;
-        comment_section.edited_lv4_reply_count += 1;
+            msg!("User Address: {}", ctx.accounts.signer.key());
+            msg!("Up Voted Post From");
+            msg!("User Address: {}", post.post_owner_address.key());
+            msg!("Chat: {}", chat_name);
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Post Depth: {}", post.depth);
+            msg!("Vote Amount: {}", vote_amount);
+        }
+        else
+        {
+            post_vote_stats.post_down_vote_count += 1;
+            comment_section.post_down_vote_score += vote_amount.abs() as u128;
+            comment_section.post_down_vote_count += 1;
 
-        msg!("LO Lv4+ Reply Edited By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Message: {}", msg);
-        
-        chat_account.edited_comment_and_reply_count += 1;
-        lo_lv4_reply.msg = msg;
+            if post.depth == 0
+            {
+                chat.comment_down_vote_count += 1;
+                comment_section.comment_down_vote_score += vote_amount.abs() as u128;
+                comment_section.comment_down_vote_count += 1;
+            }
+            else
+            {
+                chat.reply_down_vote_count += 1;
+                comment_section.reply_down_vote_score += vote_amount.abs() as u128;
+                comment_section.reply_down_vote_count += 1;
+            }
+
+            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
+            voter_chat_account.down_vote_casted_count += 1;
+            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
+            canidate_chat_account.down_vote_received_count += 1;
+
+            msg!("User Address: {}", ctx.accounts.signer.key());
+            msg!("Down Voted Post From");
+            msg!("User Address: {}", post.post_owner_address.key());
+            msg!("Chat: {}", chat_name);
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Post Depth: {}", post.depth);
+            msg!("Vote Amount: {}", vote_amount);
+        }
 
-        if lo_lv4_reply.is_edited == false
+        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
+        post.net_vote_score += vote_amount;
+        post.hot_score = get_hot_score(post.net_vote_score, post.unix_creation_time_stamp);
+        update_trending_posts(comment_section, post.key(), post.hot_score, true);
+
+        //Voting for your own post doesn't change your reputation
+        if post.post_owner_address.key() != ctx.accounts.signer.key()
         {
-            lo_lv4_reply.is_edited = true; 
+            let reputation_change = get_vote_reputation_change(&ctx.accounts.reputation_config, vote_amount)?;
+            update_reputation(canidate_chat_account, &ctx.accounts.reputation_config, reputation_change)?;
         }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        //Quadratic pricing charges for the votes this voter already cast on the post
+        let vote_cost_units = add_to_vote_tally(&mut ctx.accounts.vote_tally, post.key(), ctx.accounts.signer.key(), comment_section.vote_pricing_mode, vote_amount)?;
+        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
+
+        emit!(VoteCast
+        {
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            voter_address: ctx.accounts.signer.key(),
+            canidate_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            vote_amount,
+            net_vote_score: post.net_vote_score
+        });
+
+        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
+        if is_up_vote
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.up_vote_fee.checked_mul(vote_cost_units).ok_or(InvalidOperationError::FeeAmountOverflow)?,
                 accounts.fee_token_entry.decimal_amount
             )?;
-        }
-     
-        Ok(())
-    }
-
-    pub fn delete_m4a_comment(ctx: Context<DeleteM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
-    {
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
 
-        //You can't delete a comment that has already been deleted
-        require!(m4a_comment.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't delete a comment that isn't yours
-        require_keys_eq!(m4a_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotCommentOwner);
-        
-        m4a_chat.deleted_comment_count += 1;
-        comment_section.deleted_comment_count += 1;
-
-        msg!("PLI Comment Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-
-        chat_account.deleted_comment_and_reply_count += 1;
-        m4a_comment.is_deleted = true;
+            //Call the helper function to transfer the fee to the post owner
+            send_turd_of_tree(
+                accounts.user_ata.to_account_info(),
+                accounts.post_owner_usdc_ata.to_account_info(),
+                accounts.signer.to_account_info(),
+                accounts.token_program.to_account_info(),
+                accounts.token_mint.to_account_info(),
+                AuthorTip
+                {
+                    post_owner_address: canidate_address.key(),
+                    amount: accounts.fee_schedule.author_tip_share.checked_mul(vote_cost_units).ok_or(InvalidOperationError::FeeAmountOverflow)?,
+                    decimal_amount: accounts.fee_token_entry.decimal_amount
+                }
+            )?;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+            ctx.accounts.treasury_vault.up_vote_revenue += fee_amount as u128;
+        }
+        else
         {
             let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                accounts.fee_schedule.down_vote_fee.checked_mul(vote_cost_units).ok_or(InvalidOperationError::FeeAmountOverflow)?,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.down_vote_revenue += fee_amount as u128;
         }
-              
+
         Ok(())
     }
 
-    pub fn delete_m4a_reply(ctx: Context<DeleteM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn set_post_vote(ctx: Context<SetPostVote>, params: SetPostVoteParams) -> Result<()> 
     {
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
+        let SetPostVoteParams { chat_name, comment_section_name_prefix, comment_section_name, canidate_address, vote_amount, .. } = params;
 
-        //You can't delete a reply that has already been deleted
-        require!(m4a_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Can't vote while voting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_VOTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(m4a_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.deleted_reply_count += 1;
-        comment_section.deleted_reply_count += 1;
+        //You can't vote in a disabled comment section
+        require!(ctx.accounts.comment_section.is_disabled == false, InvalidOperationError::CommentSectionDisabled);
 
-        msg!("M4A Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        //Only chats with changeable votes take votes through set_post_vote
+        require!(ctx.accounts.chat.use_changeable_votes, InvalidOperationError::ChangeableVotesDisabled);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        m4a_reply.is_deleted = true;
+        let post_vote_ballot = &mut ctx.accounts.post_vote_ballot;
+        let old_vote_amount = post_vote_ballot.vote_amount;
+        let old_update_time_stamp = post_vote_ballot.unix_update_time_stamp;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        //You can't set your vote to the amount it already is
+        require!(vote_amount != old_vote_amount, InvalidOperationError::VoteUnchanged);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+        //Votes are charged per vote, so the vote amount must fit in a u64 either way
+        get_vote_count(vote_amount)?;
+
+        let post = &mut ctx.accounts.post;
+
+        //You can't vote for a post that has been deleted, but you can still retract your vote from it
+        require!(post.is_deleted == false || vote_amount == 0, InvalidOperationError::Deleted);
+
+        //You must vote for the person who wrote the post
+        require_keys_eq!(post.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
+
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        //First vote on this post by this voter
+        if post_vote_ballot.voter_address == Pubkey::default()
+        {
+            post_vote_ballot.post_address = post.key();
+            post_vote_ballot.voter_address = ctx.accounts.signer.key();
+            post_vote_ballot.canidate_address = canidate_address.key();
+            post_vote_ballot.unix_creation_time_stamp = time_stamp;
+        }
+        else
+        {
+            post_vote_ballot.change_count += 1;
         }
-              
-        Ok(())
-    }
 
-    pub fn delete_m4a_lv3_reply(ctx: Context<DeleteM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
-    {
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
+        post_vote_ballot.vote_amount = vote_amount;
+        post_vote_ballot.unix_update_time_stamp = time_stamp;
 
-        //You can't delete a reply that has already been deleted
-        require!(m4a_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Take the old vote off the tallies and put the new one on
+        let is_self_vote = post.post_owner_address.key() == ctx.accounts.signer.key();
+        update_post_vote_tallies(ctx.accounts, is_self_vote, old_vote_amount, false)?;
+        update_post_vote_tallies(ctx.accounts, is_self_vote, vote_amount, true)?;
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(m4a_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.deleted_lv3_reply_count += 1;
-        comment_section.deleted_lv3_reply_count += 1;
+        let post = &mut ctx.accounts.post;
+        post.hot_score = get_hot_score(post.net_vote_score, post.unix_creation_time_stamp);
+
+        //A vote retracted from a deleted post doesn't put it back on the trending list
+        update_trending_posts(&mut ctx.accounts.comment_section, post.key(), post.hot_score, post.is_deleted == false);
+
+        //The new vote replaces what is left of the old vote's reputation change after decay, voting for your own post doesn't change it
+        if !is_self_vote
+        {
+            let reputation_config = &ctx.accounts.reputation_config;
+            let vote_reputation_change = get_vote_reputation_change(reputation_config, vote_amount)?;
+            let remaining_reputation_change = get_remaining_reputation_change(reputation_config, ctx.accounts.post_vote_ballot.reputation_contribution, old_update_time_stamp, ctx.accounts.canidate_chat_account.reputation_update_time_stamp)?;
+            let reputation_change = vote_reputation_change.checked_sub(remaining_reputation_change).ok_or(InvalidOperationError::ReputationOverflow)?;
+
+            update_reputation(&mut ctx.accounts.canidate_chat_account, reputation_config, reputation_change)?;
+            ctx.accounts.post_vote_ballot.reputation_contribution = vote_reputation_change;
+        }
 
-        msg!("M4A Lv3 Reply Deleted By User");
         msg!("User Address: {}", ctx.accounts.signer.key());
+        msg!("Set Vote On Post From");
+        msg!("User Address: {}", post.post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
+        msg!("Old Vote Amount: {}", old_vote_amount);
+        msg!("New Vote Amount: {}", vote_amount);
+
+        emit!(VoteCast
+        {
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            voter_address: ctx.accounts.signer.key(),
+            canidate_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            vote_amount: vote_amount - old_vote_amount,
+            net_vote_score: post.net_vote_score
+        });
+
+        //Only the change in each direction is charged or refunded
+        let old_up_votes = get_vote_count(old_vote_amount.max(0))?;
+        let new_up_votes = get_vote_count(vote_amount.max(0))?;
+        let old_down_votes = get_vote_count(old_vote_amount.min(0))?;
+        let new_down_votes = get_vote_count(vote_amount.min(0))?;
+
+        let vote_pricing_mode = ctx.accounts.comment_section.vote_pricing_mode;
+        let accounts = &ctx.accounts;
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        m4a_lv3_reply.is_deleted = true;
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        if new_up_votes > old_up_votes
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            //Quadratic pricing charges for the up votes already on the ballot
+            let added_up_vote_units = get_vote_cost_units(vote_pricing_mode, old_up_votes, new_up_votes - old_up_votes)?;
+            let up_vote_fee = accounts.fee_schedule.up_vote_fee.checked_mul(added_up_vote_units).ok_or(InvalidOperationError::FeeAmountOverflow)?;
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                up_vote_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
-        }
-              
-        Ok(())
-    }
-
-    pub fn delete_m4a_lv4_reply(ctx: Context<DeleteM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
-    {
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
 
-        //You can't delete a reply that has already been deleted
-        require!(m4a_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
+            //Call the helper function to transfer the fee to the post owner
+            send_turd_of_tree(
+                accounts.user_ata.to_account_info(),
+                accounts.post_owner_usdc_ata.to_account_info(),
+                accounts.signer.to_account_info(),
+                accounts.token_program.to_account_info(),
+                accounts.token_mint.to_account_info(),
+                AuthorTip
+                {
+                    post_owner_address: canidate_address.key(),
+                    amount: accounts.fee_schedule.author_tip_share.checked_mul(added_up_vote_units).ok_or(InvalidOperationError::FeeAmountOverflow)?,
+                    decimal_amount: accounts.fee_token_entry.decimal_amount
+                }
+            )?;
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(m4a_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        m4a_chat.deleted_lv4_reply_count += 1;
-        comment_section.deleted_lv4_reply_count += 1;
+            ctx.accounts.treasury_vault.up_vote_revenue += fee_amount as u128;
 
-        msg!("M4A Lv4+ Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+            //Keep what was paid for the votes on the ballot so removing them refunds what was paid, not the current fee
+            let post_vote_ballot = &mut ctx.accounts.post_vote_ballot;
+            post_vote_ballot.up_vote_fees_paid = post_vote_ballot.up_vote_fees_paid.checked_add(up_vote_fee).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+        }
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        m4a_lv4_reply.is_deleted = true;
+        let accounts = &ctx.accounts;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        if new_down_votes > old_down_votes
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            //Quadratic pricing charges for the down votes already on the ballot
+            let added_down_vote_units = get_vote_cost_units(vote_pricing_mode, old_down_votes, new_down_votes - old_down_votes)?;
+            let down_vote_fee = accounts.fee_schedule.down_vote_fee.checked_mul(added_down_vote_units).ok_or(InvalidOperationError::FeeAmountOverflow)?;
 
             //Call the helper function to transfer the fee
-            apply_fee(
+            let fee_amount = apply_fee(
                 accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
+                accounts.treasury_vault_token_account.to_account_info(),
                 accounts.signer.to_account_info(),
                 accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
+                accounts.token_mint.to_account_info(),
+                down_vote_fee,
                 accounts.fee_token_entry.decimal_amount
             )?;
+
+            ctx.accounts.treasury_vault.down_vote_revenue += fee_amount as u128;
+
+            let post_vote_ballot = &mut ctx.accounts.post_vote_ballot;
+            post_vote_ballot.down_vote_fees_paid = post_vote_ballot.down_vote_fees_paid.checked_add(down_vote_fee).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+        }
+
+        //Removed votes take their share of the fees paid off the ballot
+        let post_vote_ballot = &mut ctx.accounts.post_vote_ballot;
+        let removed_up_vote_fees = get_removed_vote_fees(vote_pricing_mode, post_vote_ballot.up_vote_fees_paid, old_up_votes, new_up_votes)?;
+        let removed_down_vote_fees = get_removed_vote_fees(vote_pricing_mode, post_vote_ballot.down_vote_fees_paid, old_down_votes, new_down_votes)?;
+        post_vote_ballot.up_vote_fees_paid -= removed_up_vote_fees;
+        post_vote_ballot.down_vote_fees_paid -= removed_down_vote_fees;
+
+        //The treasury part of the fee paid for removed votes is refunded if the fee policy allows it, author tips are never refunded
+        if ctx.accounts.fee_schedule.refund_vote_fees_on_change
+        {
+            let refund_in_cents = removed_up_vote_fees.checked_add(removed_down_vote_fees).ok_or(InvalidOperationError::FeeAmountOverflow)?;
+
+            if refund_in_cents > 0
+            {
+                //The vote's fee transfers moved tokens into the vault, so reload its balance before capping the refund by it
+                ctx.accounts.treasury_vault_token_account.reload()?;
+
+                //Call the helper function to refund the fee
+                let refund_amount = refund_fee(ctx.accounts, ctx.bumps.treasury_vault, refund_in_cents)?;
+
+                let treasury_vault = &mut ctx.accounts.treasury_vault;
+                if old_up_votes > new_up_votes
+                {
+                    treasury_vault.up_vote_revenue = treasury_vault.up_vote_revenue.saturating_sub(refund_amount as u128);
+                }
+                else
+                {
+                    treasury_vault.down_vote_revenue = treasury_vault.down_vote_revenue.saturating_sub(refund_amount as u128);
+                }
+            }
+        }
+
+        //A retracted vote closes the ballot and gives the rent back to the voter
+        if vote_amount == 0
+        {
+            ctx.accounts.post_vote_ballot.close(ctx.accounts.signer.to_account_info())?;
         }
-              
+
         Ok(())
     }
 
-    pub fn delete_pli_comment(ctx: Context<DeletePLIComment>,
+    pub fn star_post(ctx: Context<StarPost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        post_owner_address: Pubkey,
+        chat_account_post_count_index: u128) -> Result<()> 
     {
-        let pli_chat = &mut ctx.accounts.pli_chat;
+        //Only the CEO or a moderator with the star permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_STAR)?;
+
+        let post = &mut ctx.accounts.post;
+        //Can't set flag to the same state because of the counters
+        require!(post.is_starred != true, InvalidOperationError::FlagSameState);
+
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let pli_comment = &mut ctx.accounts.pli_comment;
+        let idea_stats = &mut ctx.accounts.idea_stats;
+        let idea = &mut ctx.accounts.idea;
 
-        //You can't delete a comment that has already been deleted
-        require!(pli_comment.is_deleted == false, InvalidOperationError::Deleted);
+        idea_stats.protocol_idea_count += 1;
+        idea.id = idea_stats.protocol_idea_count;
+        idea.post_depth = post.depth;
 
-        //You can't delete a comment that isn't yours
-        require_keys_eq!(pli_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotCommentOwner);
-        
-        pli_chat.deleted_comment_count += 1;
-        comment_section.deleted_comment_count += 1;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        msg!("PLI Comment Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        idea.chat_name = chat_name.clone();
+        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
+        idea.comment_section_name = comment_section_name.clone();
+        idea.post_owner_address = post_owner_address;
+        idea.chat_account_post_count_index = chat_account_post_count_index;
+        idea.idea = post.msg.clone();
+        idea.unix_creation_time_stamp = time_stamp;
+
+        if post.depth == 0
+        {
+            chat.ceo_starred_comment_count += 1;
+            comment_section.ceo_starred_comment_count += 1;
+        }
+        else
+        {
+            chat.ceo_starred_reply_count += 1;
+            comment_section.ceo_starred_reply_count += 1;
+        }
+
+        chat_account.ceo_starred_comment_and_reply_count += 1;
+        idea.reputation_contribution = ctx.accounts.reputation_config.star_weight as i128;
+        update_reputation(chat_account, &ctx.accounts.reputation_config, idea.reputation_contribution)?;
+        post.is_starred = true;
+
+        msg!("Post Starred By CEO");
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        pli_comment.is_deleted = true;
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        emit!(Starred
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            is_starred: true
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_pli_reply(ctx: Context<DeletePLIReply>,
+    pub fn unstar_post(ctx: Context<UnstarPost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        post_owner_address: Pubkey,
+        _chat_account_post_count_index: u128) -> Result<()> 
     {
-        let pli_chat = &mut ctx.accounts.pli_chat;
+        //Only the CEO or a moderator with the star permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_STAR)?;
+
+        let post = &mut ctx.accounts.post;
+        //Can't set flag to the same state because of the counters
+        require!(post.is_starred != false, InvalidOperationError::FlagSameState);
+
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let pli_reply = &mut ctx.accounts.pli_reply;
+        let idea_stats = &mut ctx.accounts.idea_stats;
 
-        //You can't delete a reply that has already been deleted
-        require!(pli_reply.is_deleted == false, InvalidOperationError::Deleted);
+        idea_stats.protocol_deleted_idea_count += 1;
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(pli_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.deleted_reply_count += 1;
-        comment_section.deleted_reply_count += 1;
+        if post.depth == 0
+        {
+            chat.ceo_starred_comment_count -= 1;
+            comment_section.ceo_starred_comment_count -= 1;
+        }
+        else
+        {
+            chat.ceo_starred_reply_count -= 1;
+            comment_section.ceo_starred_reply_count -= 1;
+        }
 
-        msg!("PLI Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        chat_account.ceo_starred_comment_and_reply_count -= 1;
+
+        //Only what is left of the star's reputation change after decay is taken back
+        let idea = &ctx.accounts.idea;
+        let reputation_change = get_remaining_reputation_change(&ctx.accounts.reputation_config, idea.reputation_contribution, idea.unix_creation_time_stamp, chat_account.reputation_update_time_stamp)?;
+        update_reputation(chat_account, &ctx.accounts.reputation_config, -reputation_change)?;
+        post.is_starred = false;
+
+        msg!("Post Unstarred By CEO");
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        pli_reply.is_deleted = true;
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        emit!(Starred
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            is_starred: false
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_pli_lv3_reply(ctx: Context<DeletePLILv3Reply>,
+    pub fn set_idea_implemented_flag(ctx: Context<SetIdeaImplementedFlag>,
         comment_section_name_prefix: String,
         comment_section_name: String,
+        post_owner_address: Pubkey,
         _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        is_implemented: bool) -> Result<()> 
     {
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
+        //Only the CEO or a moderator with the manage ideas permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_IDEAS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(pli_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        let idea = &mut ctx.accounts.idea;
+        //Can't set flag to the same state
+        require!(idea.is_implemented != is_implemented, InvalidOperationError::FlagSameState);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(pli_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.deleted_lv3_reply_count += 1;
-        comment_section.deleted_lv3_reply_count += 1;
+        let idea_stats = &mut ctx.accounts.idea_stats;
+        idea_stats.updated_idea_count += 1;
 
-        msg!("PLI Lv3 Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        if is_implemented
+        {
+            let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        pli_lv3_reply.is_deleted = true;
+            idea.is_implemented = true;
+            idea.implementation_time = time_stamp;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+            msg!("Idea Implemented By CEO");
+            msg!("For User: {}", post_owner_address.key());
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Idea: {}", idea.idea);
+        }
+        else
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            idea.is_implemented = false;
+            idea.implementation_time = 0;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            msg!("Idea Unimplemented By CEO");
+            msg!("For User: {}", post_owner_address.key());
+            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+            msg!("Comment Section: {}", comment_section_name);
+            msg!("Idea: {}", idea.idea);
         }
-              
+
+        emit!(IdeaImplemented
+        {
+            chat_name: idea.chat_name.clone(),
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: idea.chat_account_post_count_index,
+            is_implemented,
+            implementation_time: idea.implementation_time
+        });
+            
         Ok(())
     }
 
-    pub fn delete_pli_lv4_reply(ctx: Context<DeletePLILv4Reply>,
+    pub fn update_idea(ctx: Context<UpdateIdea>,
         comment_section_name_prefix: String,
         comment_section_name: String,
+        post_owner_address: Pubkey,
         _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        updated_idea: String) -> Result<()> 
     {
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-
-        //You can't delete a reply that has already been deleted
-        require!(pli_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(pli_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        pli_chat.deleted_lv4_reply_count += 1;
-        comment_section.deleted_lv4_reply_count += 1;
+        //Only the CEO or a moderator with the manage ideas permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_IDEAS)?;
 
-        msg!("PLI Lv4+ Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        //Message string must not be longer than 444 characters
+        require!(updated_idea.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        pli_lv4_reply.is_deleted = true;
+        let idea_stats = &mut ctx.accounts.idea_stats;
+        let idea = &mut ctx.accounts.idea;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        if idea.is_updated == false
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+            idea.is_updated = true
         }
-              
+
+        idea.idea = updated_idea;
+        idea_stats.updated_idea_count += 1;
+
+        msg!("Idea Edited By CEO");
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
+        msg!("Comment Section: {}", comment_section_name);
+        msg!("Edited Idea: {}", idea.idea);
+            
         Ok(())
     }
 
-    pub fn delete_about_comment(ctx: Context<DeleteAboutComment>,
+    pub fn fed_post(ctx: Context<FEDPost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        post_owner_address: Pubkey,
+        chat_account_post_count_index: u128) -> Result<()> 
     {
-        let about_chat = &mut ctx.accounts.about_chat;
+        //Only the CEO or a moderator with the FED permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_FED)?;
+
+        let post = &mut ctx.accounts.post;
+        //Can't set flag to the same state because of the counters
+        require!(post.is_fed != true, InvalidOperationError::FlagSameState);
+
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let about_comment = &mut ctx.accounts.about_comment;
+        let fed_stats = &mut ctx.accounts.fed_stats;
+        let fed_record = &mut ctx.accounts.fed_record;
 
-        //You can't delete a comment that has already been deleted
-        require!(about_comment.is_deleted == false, InvalidOperationError::Deleted);
+        fed_stats.federal_agent_post_count += 1;
+        fed_record.id = fed_stats.federal_agent_post_count;
+        fed_record.post_depth = post.depth;
 
-        //You can't delete a comment that isn't yours
-        require_keys_eq!(about_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotCommentOwner);
-        
-        about_chat.deleted_comment_count += 1;
-        comment_section.deleted_comment_count += 1;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        msg!("About Comment Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        fed_record.chat_name = chat_name.clone();
+        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
+        fed_record.comment_section_name = comment_section_name.clone();
+        fed_record.post_owner_address = post_owner_address;
+        fed_record.chat_account_post_count_index = chat_account_post_count_index;
+        fed_record.post = post.msg.clone();
+        fed_record.mark_time = time_stamp;
+
+        if post.is_edited == true
+        {
+            fed_record.was_edited_before_mark = true;
+        }
+
+        if post.depth == 0
+        {
+            chat.ceo_marked_fed_comment_count += 1;
+            comment_section.ceo_marked_fed_comment_count += 1;
+        }
+        else
+        {
+            chat.ceo_marked_fed_reply_count += 1;
+            comment_section.ceo_marked_fed_reply_count += 1;
+        }
+
+        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
+        fed_record.reputation_contribution = ctx.accounts.reputation_config.fed_weight as i128;
+        update_reputation(chat_account, &ctx.accounts.reputation_config, fed_record.reputation_contribution)?;
+        post.is_fed = true;
+
+        msg!("Post Marked As Federal Agent By CEO");
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        about_comment.is_deleted = true;
-
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        emit!(FedMarked
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            is_fed: true
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_about_reply(ctx: Context<DeleteAboutReply>,
+    pub fn unfed_post(ctx: Context<UnFEDPost>,
+        chat_name: String,
         comment_section_name_prefix: String,
         comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+        post_owner_address: Pubkey,
+        _chat_account_post_count_index: u128) -> Result<()> 
     {
-        let about_chat = &mut ctx.accounts.about_chat;
+        //Only the CEO or a moderator with the FED permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_FED)?;
+
+        let post = &mut ctx.accounts.post;
+        //Can't set flag to the same state because of the counters
+        require!(post.is_fed != false, InvalidOperationError::FlagSameState);
+
+        let chat = &mut ctx.accounts.chat;
         let chat_account = &mut ctx.accounts.chat_account;
         let comment_section = &mut ctx.accounts.comment_section;
-        let about_reply = &mut ctx.accounts.about_reply;
+        let fed_stats = &mut ctx.accounts.fed_stats;
 
-        //You can't delete a reply that has already been deleted
-        require!(about_reply.is_deleted == false, InvalidOperationError::Deleted);
+        fed_stats.deleted_federal_agent_post_count += 1;
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(about_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.deleted_reply_count += 1;
-        comment_section.deleted_reply_count += 1;
+        if post.depth == 0
+        {
+            chat.ceo_marked_fed_comment_count -= 1;
+            comment_section.ceo_marked_fed_comment_count -= 1;
+        }
+        else
+        {
+            chat.ceo_marked_fed_reply_count -= 1;
+            comment_section.ceo_marked_fed_reply_count -= 1;
+        }
 
-        msg!("About Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
+        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
+
+        //Only what is left of the FED mark's reputation change after decay is taken back
+        let fed_record = &ctx.accounts.fed_record;
+        let reputation_change = get_remaining_reputation_change(&ctx.accounts.reputation_config, fed_record.reputation_contribution, fed_record.mark_time, chat_account.reputation_update_time_stamp)?;
+        update_reputation(chat_account, &ctx.accounts.reputation_config, -reputation_change)?;
+        post.is_fed = false;
+
+        msg!("Post Unmarked As Federal Agent By CEO");
+        msg!("For User: {}", post_owner_address.key());
+        msg!("Chat: {}", chat_name);
         msg!("Comment Section Prefix: {}", comment_section_name_prefix);
         msg!("Comment Section: {}", comment_section_name);
+        msg!("Post Depth: {}", post.depth);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        about_reply.is_deleted = true;
-              
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
+        emit!(FedMarked
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
+            chat_name,
+            comment_section_name_prefix,
+            comment_section_name,
+            post_owner_address,
+            chat_account_post_count_index: post.chat_account_post_count_index,
+            depth: post.depth,
+            is_fed: false
+        });
 
         Ok(())
     }
 
-    pub fn delete_about_lv3_reply(ctx: Context<DeleteAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn create_poll(ctx: Context<CreatePoll>, poll_name: String, opens_at: u64, closes_at: u64) -> Result<()> 
     {
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(about_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Poll name string must not be longer than 144 characters
+        require!(poll_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(about_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.deleted_lv3_reply_count += 1;
-        comment_section.deleted_lv3_reply_count += 1;
+        //Poll must close after it opens
+        require!(is_valid_poll_window(opens_at, closes_at), InvalidOperationError::InvalidPollWindow);
 
-        msg!("About Lv3 Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        about_lv3_reply.is_deleted = true;
+        poll.is_active = true;
+        poll.poll_index = poll_stats.poll_count;
+        poll.poll_name = poll_name.clone();
+        poll.unix_creation_time_stamp = time_stamp;
+        poll.opens_at = opens_at;
+        poll.closes_at = closes_at;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        poll_stats.poll_count += 1;
+
+        msg!("New Poll Created: #{}", poll_stats.poll_count);
+        msg!("Poll Name: {}", poll_name);
+        msg!("Opens At: {}, Closes At: {}", opens_at, closes_at);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_about_lv4_reply(ctx: Context<DeleteAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn edit_poll(ctx: Context<EditPoll>, _poll_index: u128, poll_name: String, opens_at: u64, closes_at: u64) -> Result<()> 
     {
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(about_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Poll name string must not be longer than 144 characters
+        require!(poll_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(about_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        about_chat.deleted_lv4_reply_count += 1;
-        comment_section.deleted_lv4_reply_count += 1;
+        //Poll must close after it opens
+        require!(is_valid_poll_window(opens_at, closes_at), InvalidOperationError::InvalidPollWindow);
 
-        msg!("About Lv4+ Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        about_lv4_reply.is_deleted = true;
+        //Finalized polls can't be reopened
+        require!(poll.is_finalized == false, InvalidOperationError::PollFinalized);
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        poll.poll_name = poll_name;
+        poll.opens_at = opens_at;
+        poll.closes_at = closes_at;
+        poll_stats.edited_poll_or_poll_option_count += 1;
+
+        msg!("Edited Poll");
+        msg!("New Poll Name: {}", poll.poll_name);
+        msg!("Opens At: {}, Closes At: {}", opens_at, closes_at);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_lo_comment(ctx: Context<DeleteLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn set_poll_flag(ctx: Context<SetPollFlag>, _poll_index: u128, is_active: bool) -> Result<()> 
     {
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_comment = &mut ctx.accounts.lo_comment;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a comment that has already been deleted
-        require!(lo_comment.is_deleted == false, InvalidOperationError::Deleted);
+        let poll = &mut ctx.accounts.poll;
+        //Can't set flag to the same state
+        require!(poll.is_active != is_active, InvalidOperationError::FlagSameState);
 
-        //You can't delete a comment that isn't yours
-        require_keys_eq!(lo_comment.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotCommentOwner);
-        
-        lo_chat.deleted_comment_count += 1;
-        comment_section.deleted_comment_count += 1;
+        //Finalized polls can't be reactivated
+        require!(poll.is_finalized == false, InvalidOperationError::PollFinalized);
 
-        msg!("LO Comment Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-
-        chat_account.deleted_comment_and_reply_count += 1;
-        lo_comment.is_deleted = true;
+        let poll_stats = &mut ctx.accounts.poll_stats;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        poll.is_active = is_active;
+        poll_stats.edited_poll_or_poll_option_count += 1;
+        
+        msg!("Updated Poll Active Flag To: {}", is_active);
+        msg!("Poll Name: {}", poll.poll_name);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_lo_reply(ctx: Context<DeleteLOReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn create_poll_option(ctx: Context<CreatePollOption>, _poll_index: u128, poll_option_name: String) -> Result<()> 
     {
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_reply = &mut ctx.accounts.lo_reply;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(lo_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Poll option name string must not be longer than 144 characters
+        require!(poll_option_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(lo_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.deleted_reply_count += 1;
-        comment_section.deleted_reply_count += 1;
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
+        let poll_option = &mut ctx.accounts.poll_option;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-        msg!("LO Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        poll_option.is_active = true;
+        poll_option.poll_option_index = poll.option_count;
+        poll_option.poll_option_name = poll_option_name;
+        poll_option.unix_creation_time_stamp = time_stamp;
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        lo_reply.is_deleted = true;
+        poll_stats.option_count += 1;
+        poll.option_count += 1;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("New Poll Option Created");
+        msg!("Poll Name: {}", poll.poll_name);
+        msg!("Poll Option Name: {}", poll_option.poll_option_name);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_lo_lv3_reply(ctx: Context<DeleteLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn edit_poll_option(ctx: Context<EditPollOption>, _poll_index: u128, _poll_option_index: u8, poll_option_name: String) -> Result<()> 
     {
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(lo_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
+        //Poll option name string must not be longer than 144 characters
+        require!(poll_option_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
+
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
+        let poll_option = &mut ctx.accounts.poll_option;
+
+        poll_stats.edited_poll_or_poll_option_count += 1;
+        poll.edited_poll_option_count += 1;
+        poll_option.poll_option_name = poll_option_name;
+
+        msg!("Edited Poll Option");
+        msg!("Poll: {}", poll.poll_name);
+        msg!("New Poll Option Name: {}", poll_option.poll_option_name);
+
+        Ok(())
+    }
+
+    pub fn set_poll_option_flag(ctx: Context<SetPollOptionFlag>,
+        _poll_index: u128,
+        _poll_option_index: u8,
+        is_active: bool) -> Result<()> 
+    {
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
+
+        let poll_option = &mut ctx.accounts.poll_option;
+        //Can't set flag to the same state
+        require!(poll_option.is_active != is_active, InvalidOperationError::FlagSameState);
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(lo_lv3_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
+        let poll = &mut ctx.accounts.poll;
+        let poll_stats = &mut ctx.accounts.poll_stats;
         
-        lo_chat.deleted_lv3_reply_count += 1;
-        comment_section.deleted_lv3_reply_count += 1;
+        poll_stats.edited_poll_or_poll_option_count += 1;
+        poll.edited_poll_option_count += 1;
+        poll_option.is_active = is_active;
 
-        msg!("LO Lv3 Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        msg!("Updated Poll Option Active Flag To: {}", is_active);
+        msg!("Poll: {}", poll.poll_name);
+        msg!("Poll Option: {}", poll_option.poll_option_name);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        lo_lv3_reply.is_deleted = true;
+        Ok(())
+    }
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+    pub fn delete_poll_option(ctx: Context<DeletePollOption>, _poll_index: u128, _poll_option_index: u8) -> Result<()> 
+    {
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
+
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
+        let poll_option = &mut ctx.accounts.poll_option;
+
+        //Finalized polls keep their results, so their options can't be deleted
+        require!(poll.is_finalized == false, InvalidOperationError::PollFinalized);
+
+        poll_stats.deleted_option_count += 1;
+        poll.deleted_option_count += 1;
+
+        //The deleted option's votes come off the aggregate tallies so they only cover the remaining options
+        poll_stats.up_vote_score -= poll_option.up_vote_score;
+        poll_stats.down_vote_score -= poll_option.down_vote_score;
+        poll.up_vote_score -= poll_option.up_vote_score;
+        poll.down_vote_score -= poll_option.down_vote_score;
+        poll.up_vote_count -= poll_option.up_vote_count;
+        poll.down_vote_count -= poll_option.down_vote_count;
+
+        msg!("Deleted Poll Option");
+        msg!("Poll: {}", poll.poll_name);
+        msg!("Poll Option: {}", poll_option.poll_option_name);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn delete_lo_lv4_reply(ctx: Context<DeleteLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey) -> Result<()> 
+    pub fn delete_poll(ctx: Context<DeletePoll>, _poll_index: u128) -> Result<()> 
     {
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
+        //Only the CEO or a moderator with the manage polls permission can call this function
+        require_ceo_or_moderator(&ctx.accounts.signer, &ctx.accounts.ceo, &ctx.accounts.moderator, MODERATOR_PERMISSION_MANAGE_POLLS)?;
 
-        //You can't delete a reply that has already been deleted
-        require!(lo_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll = &mut ctx.accounts.poll;
 
-        //You can't delete a reply that isn't yours
-        require_keys_eq!(lo_lv4_reply.post_owner_address.key(), ctx.accounts.signer.key(), AuthorizationError::NotReplyOwner);
-        
-        lo_chat.deleted_lv4_reply_count += 1;
-        comment_section.deleted_lv4_reply_count += 1;
+        //Finalized polls keep their results, so they can't be deleted
+        require!(poll.is_finalized == false, InvalidOperationError::PollFinalized);
 
-        msg!("LO Lv4+ Reply Deleted By User");
-        msg!("User Address: {}", ctx.accounts.signer.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
+        //Can't delete a poll until all of its options have been deleted
+        require!(poll.option_count == poll.deleted_option_count, InvalidOperationError::PollStillHasOptions);
 
-        chat_account.deleted_comment_and_reply_count += 1;
-        lo_lv4_reply.is_deleted = true;
+        poll_stats.deleted_poll_count += 1;
 
-        let ceo = &mut ctx.accounts.ceo;
-        if ctx.accounts.signer.key() != ceo.address.key()
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        msg!("Deleted Poll");
+        msg!("Poll Name: {}", poll.poll_name);
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-              
         Ok(())
     }
 
-    pub fn m4a_comment_vote(ctx: Context<M4ACommentVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
+    pub fn vote_poll_option(ctx: Context<VotePollOption>, poll_index: u128, poll_option_index: u8, _token_mint_address: Pubkey, vote_amount: i128) -> Result<()> 
     {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
+        //Can't vote on polls while polls, voting or fees are paused
+        require!(!is_protocol_paused(&ctx.accounts.protocol_config, PAUSE_POLLS | PAUSE_VOTING | PAUSE_FEES), InvalidOperationError::ProtocolPaused);
+
+        //You can't vote on a poll that isn't active
+        require!(ctx.accounts.poll.is_active, InvalidOperationError::PollNotActive);
+
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
+
+        //You can't vote on a poll before it opens
+        require!(time_stamp >= ctx.accounts.poll.opens_at, InvalidOperationError::PollNotOpen);
 
-        //You can't vote for a comment that has been deleted
-        require!(m4a_comment.is_deleted == false, InvalidOperationError::Deleted);
+        //You can't vote on a poll after it closes
+        require!(ctx.accounts.poll.closes_at == 0 || time_stamp < ctx.accounts.poll.closes_at, InvalidOperationError::PollClosed);
 
-        //You must vote for the person who wrote the comment
-        require_keys_eq!(m4a_comment.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
+        //You can't vote on a poll option that isn't active
+        require!(ctx.accounts.poll_option.is_active, InvalidOperationError::PollOptionNotActive);
 
         //You can not vote a 0 ammount
         require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
 
+        let vote_count = get_vote_count(vote_amount)?;
         let mut is_up_vote = false;
 
         if vote_amount > 0
@@ -3537,13126 +3354,152 @@ pub mod chat
             is_up_vote = true;
         }
 
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-    
-        //Add code to account for voter voting for their own comment since can't duplicate accounts
-        if m4a_comment.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }  
-        }
+        let chat_account = &mut ctx.accounts.chat_account;
+        
+        let poll_stats = &mut ctx.accounts.poll_stats;
+        let poll_vote_stats = &mut ctx.accounts.poll_vote_stats;
+        let poll = &mut ctx.accounts.poll;
+        let poll_option = &mut ctx.accounts.poll_option;
+        let poll_vote_record = &mut ctx.accounts.poll_vote_record;
 
         if is_up_vote
         {
-            post_vote_stats.post_up_vote_count += 1;
-            m4a_chat.comment_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.comment_up_vote_score += vote_amount as u128;
-            comment_section.comment_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
+            poll_stats.up_vote_score += vote_count as u128;
+            poll_vote_stats.up_vote_count += 1;
+            poll.up_vote_score += vote_count as u128;
+            poll.up_vote_count += 1;
+            poll_option.up_vote_score += vote_count as u128;
+            poll_option.up_vote_count += 1;
+           
             msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted M4A Comment From");
-            msg!("User Address: {}", m4a_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
+            msg!("Up Voted Poll Option");
+            msg!("Poll: {}", poll.poll_name);
+            msg!("Poll Option: {}", poll_option.poll_option_name);
             msg!("Vote Amount: {}", vote_amount);
         }
         else
         {
-            post_vote_stats.post_down_vote_count += 1;
-            m4a_chat.comment_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.comment_down_vote_score += vote_amount.abs() as u128;
-            comment_section.comment_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
+            poll_stats.down_vote_score += vote_count as u128;
+            poll_vote_stats.down_vote_count += 1;
+            poll.down_vote_score += vote_count as u128;
+            poll.down_vote_count += 1;
+            poll_option.down_vote_score += vote_count as u128;
+            poll_option.down_vote_count += 1;
 
             msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted M4A Comment From");
-            msg!("User Address: {}", m4a_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
+            msg!("Down Voted Poll Option");
+            msg!("Poll: {}", poll.poll_name);
+            msg!("Poll Option: {}", poll_option.poll_option_name);
             msg!("Vote Amount: {}", vote_amount);
         }
 
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        m4a_comment.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+        poll_vote_record.vote_amount = vote_amount;
+        poll_vote_record.protocol_record_id = poll_vote_stats.up_vote_count + poll_vote_stats.down_vote_count;
+        poll.vote_record_count += 1;
+        poll_vote_record.poll_record_id = poll.vote_record_count;
+        poll_vote_record.poll_index = poll_index;
+        poll_vote_record.poll_option_index = poll_option_index;
+        poll_vote_record.voter_address = ctx.accounts.signer.key();
+        poll_vote_record.unix_creation_time_stamp = time_stamp;
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
+        chat_account.poll_vote_count += 1;
 
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
+        emit!(PollVoteCast
         {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
+            voter_address: ctx.accounts.signer.key(),
+            poll_index,
+            poll_option_index,
+            vote_amount
+        });
 
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn m4a_reply_vote(ctx: Context<M4AReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(m4a_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(m4a_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't dum4acate accounts
-        if m4a_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }  
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            m4a_chat.reply_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted M4A Reply From");
-            msg!("User Address: {}", m4a_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            m4a_chat.reply_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted M4A Reply From");
-            msg!("User Address: {}", m4a_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        m4a_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn m4a_lv3_reply_vote(ctx: Context<M4ALv3ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(m4a_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(m4a_lv3_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't dum4acate accounts
-        if m4a_lv3_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            m4a_chat.reply_lv3_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv3_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted M4A Lv3 Reply From");
-            msg!("User Address: {}", m4a_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            m4a_chat.reply_lv3_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv3_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted M4A Lv3 Reply From");
-            msg!("User Address: {}", m4a_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        m4a_lv3_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn m4a_lv4_reply_vote(ctx: Context<M4ALv4ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(m4a_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(m4a_lv4_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't dum4acate accounts
-        if m4a_lv4_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }  
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            m4a_chat.reply_lv4_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_lv3_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv4_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted M4A Lv4+ Reply From");
-            msg!("User Address: {}", m4a_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            m4a_chat.reply_lv4_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_lv3_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv4_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted M4A Lv4+ Reply From");
-            msg!("User Address: {}", m4a_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        m4a_lv4_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn pli_comment_vote(ctx: Context<PLICommentVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a comment that has been deleted
-        require!(pli_comment.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the comment
-        require_keys_eq!(pli_comment.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own comment since can't duplicate accounts
-        if pli_comment.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }  
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            pli_chat.comment_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.comment_up_vote_score += vote_amount as u128;
-            comment_section.comment_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted PLI Comment From");
-            msg!("User Address: {}", pli_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            pli_chat.comment_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.comment_down_vote_score += vote_amount.abs() as u128;
-            comment_section.comment_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted PLI Comment From");
-            msg!("User Address: {}", pli_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        pli_comment.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn pli_reply_vote(ctx: Context<PLIReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(pli_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(pli_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if pli_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            pli_chat.reply_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted PLI Reply From");
-            msg!("User Address: {}", pli_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            pli_chat.reply_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted PLI Reply From");
-            msg!("User Address: {}", pli_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        pli_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn pli_lv3_reply_vote(ctx: Context<PLILv3ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(pli_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(pli_lv3_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if pli_lv3_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }  
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            pli_chat.reply_lv3_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv3_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted PLI Lv3 Reply From");
-            msg!("User Address: {}", pli_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            pli_chat.reply_lv3_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv3_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted PLI Lv3 Reply From");
-            msg!("User Address: {}", pli_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        pli_lv3_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn pli_lv4_reply_vote(ctx: Context<PLILv4ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(pli_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(pli_lv4_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if pli_lv4_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            pli_chat.reply_lv4_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_lv3_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv4_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted PLI Lv4+ Reply From");
-            msg!("User Address: {}", pli_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            pli_chat.reply_lv4_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_lv3_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv4_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted PLI Lv4+ Reply From");
-            msg!("User Address: {}", pli_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        pli_lv4_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn about_comment_vote(ctx: Context<AboutCommentVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_comment = &mut ctx.accounts.about_comment;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a comment that has been deleted
-        require!(about_comment.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the comment
-        require_keys_eq!(about_comment.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own comment since can't duplicate accounts
-        if about_comment.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            about_chat.comment_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.comment_up_vote_score += vote_amount as u128;
-            comment_section.comment_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted About Comment From");
-            msg!("User Address: {}", about_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            about_chat.comment_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.comment_down_vote_score += vote_amount.abs() as u128;
-            comment_section.comment_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted About Comment From");
-            msg!("User Address: {}", about_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        about_comment.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn about_reply_vote(ctx: Context<AboutReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_reply = &mut ctx.accounts.about_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(about_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(about_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if about_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            about_chat.reply_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted About Reply From");
-            msg!("User Address: {}", about_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            about_chat.reply_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted About Reply From");
-            msg!("User Address: {}", about_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        about_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn about_lv3_reply_vote(ctx: Context<AboutLv3ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(about_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(about_lv3_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if about_lv3_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            about_chat.reply_lv3_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv3_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted About Lv3 Reply From");
-            msg!("User Address: {}", about_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            about_chat.reply_lv3_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv3_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted About Lv3 Reply From");
-            msg!("User Address: {}", about_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;    
-        about_lv3_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn about_lv4_reply_vote(ctx: Context<AboutLv4ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let about_chat = &mut ctx.accounts.about_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(about_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(about_lv4_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if about_lv4_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            about_chat.reply_lv4_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_lv3_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv4_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted About Lv4+ Reply From");
-            msg!("User Address: {}", about_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            about_chat.reply_lv4_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_lv3_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv4_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted About Lv4+ Reply From");
-            msg!("User Address: {}", about_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        about_lv4_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn lo_comment_vote(ctx: Context<LOCommentVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a comment that has been deleted
-        require!(lo_comment.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the comment
-        require_keys_eq!(lo_comment.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own comment since can't duplicate accounts
-        if lo_comment.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            lo_chat.comment_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.comment_up_vote_score += vote_amount as u128;
-            comment_section.comment_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted LO Comment From");
-            msg!("User Address: {}", lo_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            lo_chat.comment_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.comment_down_vote_score += vote_amount.abs() as u128;
-            comment_section.comment_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted LO Comment From");
-            msg!("User Address: {}", lo_comment.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;  
-        lo_comment.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn lo_reply_vote(ctx: Context<LOReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(lo_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(lo_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if lo_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            lo_chat.reply_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted LO Reply From");
-            msg!("User Address: {}", lo_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            lo_chat.reply_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted LO Reply From");
-            msg!("User Address: {}", lo_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        lo_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn lo_lv3_reply_vote(ctx: Context<LOLv3ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(lo_lv3_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(lo_lv3_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if lo_lv3_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            lo_chat.reply_lv3_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv3_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted LO Lv3 Reply From");
-            msg!("User Address: {}", lo_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            lo_chat.reply_lv3_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv3_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted LO Lv3 Reply From");
-            msg!("User Address: {}", lo_lv3_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;
-        lo_lv3_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn lo_lv4_reply_vote(ctx: Context<LOLv4ReplyVote>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        canidate_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        _token_mint_address: Pubkey,
-        vote_amount: i128) -> Result<()> 
-    {
-        let post_vote_stats = &mut ctx.accounts.post_vote_stats;
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let canidate_chat_account = &mut ctx.accounts.canidate_chat_account;
-        let voter_chat_account = &mut ctx.accounts.voter_chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        let post_vote_record = &mut ctx.accounts.post_vote_record;
-        
-        //You can't vote for a reply that has been deleted
-        require!(lo_lv4_reply.is_deleted == false, InvalidOperationError::Deleted);
-
-        //You must vote for the person who wrote the reply
-        require_keys_eq!(lo_lv4_reply.post_owner_address.key(), canidate_address.key(), InvalidOperationError::WrongDude);
-
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        post_vote_record.voter_address = ctx.accounts.signer.key();
-        post_vote_record.canidate_address = canidate_address.key();
-        post_vote_record.unix_creation_time_stamp = Clock::get()?.unix_timestamp as u64;
-        post_vote_record.vote_amount = vote_amount;
-
-        //Add code to account for voter voting for their own reply since can't duplicate accounts
-        if lo_lv4_reply.post_owner_address.key() == ctx.accounts.signer.key()
-        {
-            if is_up_vote
-            {
-                voter_chat_account.received_up_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.up_vote_received_count += 1;
-            }
-            else
-            {
-                voter_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-                voter_chat_account.down_vote_received_count += 1;
-            }   
-        }
-
-        if is_up_vote
-        {
-            post_vote_stats.post_up_vote_count += 1;
-            lo_chat.reply_lv4_up_vote_count += 1;
-            comment_section.post_up_vote_score += vote_amount as u128;
-            comment_section.post_up_vote_count += 1;
-            comment_section.reply_to_lv3_reply_up_vote_score += vote_amount as u128;
-            comment_section.reply_lv4_up_vote_count += 1;
-
-            voter_chat_account.casted_up_vote_score += vote_amount as u128;
-            voter_chat_account.up_vote_casted_count += 1;
-            canidate_chat_account.received_up_vote_score += vote_amount as u128;
-            canidate_chat_account.up_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted LO Lv4+ Reply From");
-            msg!("User Address: {}", lo_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            post_vote_stats.post_down_vote_count += 1;
-            lo_chat.reply_lv4_down_vote_count += 1;
-            comment_section.post_down_vote_score += vote_amount.abs() as u128;
-            comment_section.post_down_vote_count += 1;
-            comment_section.reply_to_lv3_reply_down_vote_score += vote_amount.abs() as u128;
-            comment_section.reply_lv4_down_vote_count += 1;
-
-            voter_chat_account.casted_down_vote_score += vote_amount.abs() as u128;
-            voter_chat_account.down_vote_casted_count += 1;
-            canidate_chat_account.received_down_vote_score += vote_amount.abs() as u128;
-            canidate_chat_account.down_vote_received_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted LO Lv4+ Reply From");
-            msg!("User Address: {}", lo_lv4_reply.post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        post_vote_record.id = post_vote_stats.post_up_vote_count + post_vote_stats.post_down_vote_count;   
-        lo_lv4_reply.net_vote_score += vote_amount;
-        voter_chat_account.post_vote_casted_count += 1; //This is needed for the PostVoteRecord account. Couldn't add the up_vote_casted_count and down_vote_casted_count properties in the derived account seeds
-
-        //This is in its own if block because it caused mutable to immutable borrow errors in the previous is_up_vote if block
-        if is_up_vote
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_3CENTS * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-
-            //Call the helper function to transfer the fee to the post owner
-            send_turd_of_tree(
-                accounts.user_ata.to_account_info(),
-                accounts.post_owner_usdc_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                canidate_address.key(),
-                FEE_1CENT * vote_amount as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-        else
-        {
-            let accounts = &ctx.accounts;
-            let treasurer = ctx.accounts.treasurer.clone();
-
-            //Call the helper function to transfer the fee
-            apply_fee(
-                accounts.user_ata.to_account_info(),
-                accounts.treasurer_ata.to_account_info(),
-                accounts.signer.to_account_info(),
-                accounts.token_program.to_account_info(),
-                treasurer,
-                FEE_4CENTS * vote_amount.abs() as u64,
-                accounts.fee_token_entry.decimal_amount
-            )?;
-        }
-
-        Ok(())
-    }
-
-    pub fn star_m4a_comment(ctx: Context<StarM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_comment.is_starred != true, InvalidOperationError::FlagSameState);
-    
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = m4a_comment.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        m4a_chat.ceo_starred_comment_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_comment_count += 1;
-        m4a_comment.is_starred = true;  
-
-        msg!("M4A Comment Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-  
-        Ok(())
-    }
-
-    pub fn unstar_m4a_comment(ctx: Context<UnstarM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_comment.is_starred != false, InvalidOperationError::FlagSameState);
-    
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        m4a_chat.ceo_starred_comment_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_comment_count -= 1;
-        m4a_comment.is_starred = false;  
-
-        msg!("M4A Comment Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-  
-        Ok(())
-    }
-
-    pub fn star_m4a_reply(ctx: Context<StarM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = m4a_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        m4a_chat.ceo_starred_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_reply_count += 1;
-        m4a_reply.is_starred = true;
-
-        msg!("M4A Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_m4a_reply(ctx: Context<UnstarM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        m4a_chat.ceo_starred_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_reply_count -= 1;
-        m4a_reply.is_starred = false;
-
-        msg!("M4A Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_m4a_lv3_reply(ctx: Context<StarM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv3_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = m4a_lv3_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        m4a_chat.ceo_starred_lv3_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv3_reply_count += 1;
-        m4a_lv3_reply.is_starred = true;
-
-        msg!("M4A Lv3 Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_m4a_lv3_reply(ctx: Context<UnstarM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv3_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        m4a_chat.ceo_starred_lv3_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv3_reply_count -= 1;
-        m4a_lv3_reply.is_starred = false;
-
-        msg!("M4A Lv3 Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_m4a_lv4_reply(ctx: Context<StarM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv4_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = m4a_lv4_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        m4a_chat.ceo_starred_lv4_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv4_reply_count += 1;
-        m4a_lv4_reply.is_starred = true;
-
-        msg!("M4A Lv4+ Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn unstar_m4a_lv4_reply(ctx: Context<UnstarM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv4_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        m4a_chat.ceo_starred_lv4_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv4_reply_count -= 1;
-        m4a_lv4_reply.is_starred = false;
-
-        msg!("M4A Lv4+ Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn star_pli_comment(ctx: Context<StarPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        //Can't set flag to the same state because of the counters
-        require!(pli_comment.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = pli_comment.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-        
-        pli_chat.ceo_starred_comment_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_comment_count += 1;
-        pli_comment.is_starred = true;
-
-        msg!("PLI Comment Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_pli_comment(ctx: Context<UnstarPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        //Can't set flag to the same state because of the counters
-        require!(pli_comment.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-        
-        pli_chat.ceo_starred_comment_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_comment_count -= 1;
-        pli_comment.is_starred = false;
-
-        msg!("PLI Comment Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn star_pli_reply(ctx: Context<StarPLIReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = pli_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        pli_chat.ceo_starred_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_reply_count += 1;
-        pli_reply.is_starred = true;
-
-        msg!("PLI Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_pli_reply(ctx: Context<UnstarPLIReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        pli_chat.ceo_starred_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_reply_count -= 1;
-        pli_reply.is_starred = false;
-
-        msg!("PLI Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-  
-        Ok(())
-    }
-
-    pub fn star_pli_lv3_reply(ctx: Context<StarPLILv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv3_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = pli_lv3_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        pli_chat.ceo_starred_lv3_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv3_reply_count += 1;
-        pli_lv3_reply.is_starred = true;
-
-        msg!("PLI Lv3 Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_pli_lv3_reply(ctx: Context<UnstarPLILv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv3_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        pli_chat.ceo_starred_lv3_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv3_reply_count -= 1;
-        pli_lv3_reply.is_starred = false;
-
-        msg!("PLI Lv3 Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_pli_lv4_reply(ctx: Context<StarPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv4_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = pli_lv4_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        pli_chat.ceo_starred_lv4_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv4_reply_count += 1;
-        pli_lv4_reply.is_starred = true;
-
-        msg!("PLI Lv4+ Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn unstar_pli_lv4_reply(ctx: Context<UnstarPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv4_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        pli_chat.ceo_starred_lv4_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv4_reply_count -= 1;
-        pli_lv4_reply.is_starred = false;
-
-        msg!("PLI Lv4+ Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_about_comment(ctx: Context<StarAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_comment = &mut ctx.accounts.about_comment;
-        //Can't set flag to the same state because of the counters
-        require!(about_comment.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = about_comment.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        about_chat.ceo_starred_comment_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_comment_count += 1;
-        about_comment.is_starred = true;
-
-        msg!("About Comment Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_about_comment(ctx: Context<UnstarAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_comment = &mut ctx.accounts.about_comment;
-        //Can't set flag to the same state because of the counters
-        require!(about_comment.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        about_chat.ceo_starred_comment_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_comment_count -= 1;
-        about_comment.is_starred = false;
-
-        msg!("About Comment Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_about_reply(ctx: Context<StarAboutReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_reply = &mut ctx.accounts.about_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = about_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        about_chat.ceo_starred_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_reply_count += 1;
-        about_reply.is_starred = true;
-
-        msg!("About Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_about_reply(ctx: Context<UnstarAboutReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_reply = &mut ctx.accounts.about_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        about_chat.ceo_starred_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_reply_count -= 1;
-        about_reply.is_starred = false;
-
-        msg!("About Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn star_about_lv3_reply(ctx: Context<StarAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv3_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = about_lv3_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        about_chat.ceo_starred_lv3_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv3_reply_count += 1;
-        about_lv3_reply.is_starred = true;
-
-        msg!("About Lv3 Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_about_lv3_reply(ctx: Context<UnstarAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv3_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        about_chat.ceo_starred_lv3_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv3_reply_count -= 1;
-        about_lv3_reply.is_starred = false;
-
-        msg!("About Lv3 Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_about_lv4_reply(ctx: Context<StarAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv4_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = about_lv4_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        about_chat.ceo_starred_lv4_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv4_reply_count += 1;
-        about_lv4_reply.is_starred = true;
-
-        msg!("About Lv4+ Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn unstar_about_lv4_reply(ctx: Context<UnstarAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv4_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        about_chat.ceo_starred_lv4_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv4_reply_count -= 1;
-        about_lv4_reply.is_starred = false;
-
-        msg!("About Lv4+ Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn star_lo_comment(ctx: Context<StarLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        //Can't set flag to the same state because of the counters
-        require!(lo_comment.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = lo_comment.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        lo_chat.ceo_starred_comment_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_comment_count += 1;
-        lo_comment.is_starred = true;
-
-        msg!("LO Comment Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_lo_comment(ctx: Context<UnstarLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        //Can't set flag to the same state because of the counters
-        require!(lo_comment.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        lo_chat.ceo_starred_comment_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_comment_count -= 1;
-        lo_comment.is_starred = false;
-
-        msg!("LO Comment Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_lo_reply(ctx: Context<StarLOReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = lo_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        lo_chat.ceo_starred_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_reply_count += 1;
-        lo_reply.is_starred = true;
-
-        msg!("LO Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_lo_reply(ctx: Context<UnstarLOReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        lo_chat.ceo_starred_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_reply_count -= 1;
-        lo_reply.is_starred = false;
-
-        msg!("LO Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn star_lo_lv3_reply(ctx: Context<StarLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv3_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = lo_lv3_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        lo_chat.ceo_starred_lv3_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv3_reply_count += 1;
-        lo_lv3_reply.is_starred = true;
-
-        msg!("LO Lv3 Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unstar_lo_lv3_reply(ctx: Context<UnstarLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv3_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        lo_chat.ceo_starred_lv3_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv3_reply_count -= 1;
-        lo_lv3_reply.is_starred = false;
-
-        msg!("LO Lv3 Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn star_lo_lv4_reply(ctx: Context<StarLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv4_reply.is_starred != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-        
-        idea_stats.protocol_idea_count += 1;
-        idea.id = idea_stats.protocol_idea_count;
-        idea.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        idea.comment_section_name_prefix = comment_section_name_prefix.clone();
-        idea.comment_section_name = comment_section_name.clone();
-        idea.post_owner_address = post_owner_address;
-        idea.chat_account_post_count_index = chat_account_post_count_index;
-        idea.idea = lo_lv4_reply.msg.clone();
-        idea.unix_creation_time_stamp = time_stamp;
-
-        lo_chat.ceo_starred_lv4_reply_count += 1;
-        chat_account.ceo_starred_comment_and_reply_count += 1;
-        comment_section.ceo_starred_lv4_reply_count += 1;
-        lo_lv4_reply.is_starred = true;
-
-        msg!("LO Lv4+ Reply Starred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn unstar_lo_lv4_reply(ctx: Context<UnstarLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv4_reply.is_starred != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let idea_stats = &mut ctx.accounts.idea_stats;
-
-        idea_stats.protocol_deleted_idea_count += 1;
-
-        lo_chat.ceo_starred_lv4_reply_count -= 1;
-        chat_account.ceo_starred_comment_and_reply_count -= 1;
-        comment_section.ceo_starred_lv4_reply_count -= 1;
-        lo_lv4_reply.is_starred = false;
-
-        msg!("About Lv4+ Reply Unstarred By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-            
-        Ok(())
-    }
-
-    pub fn set_idea_implemented_flag(ctx: Context<SetIdeaImplementedFlag>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        is_implemented: bool) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let idea = &mut ctx.accounts.idea;
-        //Can't set flag to the same state
-        require!(idea.is_implemented != is_implemented, InvalidOperationError::FlagSameState);
-
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        idea_stats.updated_idea_count += 1;
-
-        if is_implemented
-        {
-            let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-            idea.is_implemented = true;
-            idea.implementation_time = time_stamp;
-
-            msg!("Idea Implemented By CEO");
-            msg!("For User: {}", post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Idea: {}", idea.idea);
-        }
-        else
-        {
-            idea.is_implemented = false;
-            idea.implementation_time = 0;
-
-            msg!("Idea Unimplemented By CEO");
-            msg!("For User: {}", post_owner_address.key());
-            msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-            msg!("Comment Section: {}", comment_section_name);
-            msg!("Idea: {}", idea.idea);
-        }
-            
-        Ok(())
-    }
-
-    pub fn update_idea(ctx: Context<UpdateIdea>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128,
-        updated_idea: String) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        //Message string must not be longer than 444 characters
-        require!(updated_idea.len() <= MAX_POST_LENGTH, InvalidLengthError::MSGTooLong);
-
-        let idea_stats = &mut ctx.accounts.idea_stats;
-        let idea = &mut ctx.accounts.idea;
-
-        if idea.is_updated == false
-        {
-            idea.is_updated = true
-        }
-
-        idea.idea = updated_idea;
-        idea_stats.updated_idea_count += 1;
-
-        msg!("Idea Edited By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);
-        msg!("Edited Idea: {}", idea.idea);
-            
-        Ok(())
-    }
-
-    pub fn fed_m4a_comment(ctx: Context<FEDM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()>
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_comment.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = m4a_comment.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if m4a_comment.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-      
-        m4a_chat.ceo_marked_fed_comment_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_comment_count += 1;
-        m4a_comment.is_fed = true;
-
-        msg!("M4A Comment Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_m4a_comment(ctx: Context<UnFEDM4AComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()>
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_comment = &mut ctx.accounts.m4a_comment;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_comment.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        m4a_chat.ceo_marked_fed_comment_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_comment_count -= 1;
-        m4a_comment.is_fed = false;
-
-        msg!("M4A Comment Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name);      
-        
-        Ok(())
-    }
-
-    pub fn fed_m4a_reply(ctx: Context<FEDM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = m4a_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if m4a_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        m4a_chat.ceo_marked_fed_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_reply_count += 1;
-        m4a_reply.is_fed = true;
-
-        msg!("M4A Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_m4a_reply(ctx: Context<UnFEDM4AReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_reply = &mut ctx.accounts.m4a_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        m4a_chat.ceo_marked_fed_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_reply_count -= 1;
-        m4a_reply.is_fed = false;
-  
-        msg!("M4A Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_m4a_lv3_reply(ctx: Context<FEDM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv3_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = m4a_lv3_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if m4a_lv3_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        m4a_chat.ceo_marked_fed_lv3_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv3_reply_count += 1;
-        m4a_lv3_reply.is_fed = true;
-
-        msg!("M4A Lv3 Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_m4a_lv3_reply(ctx: Context<UnFEDM4ALv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv3_reply = &mut ctx.accounts.m4a_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv3_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        m4a_chat.ceo_marked_fed_lv3_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv3_reply_count -= 1;
-        m4a_lv3_reply.is_fed = false;
-
-        msg!("M4A Lv3 Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_m4a_lv4_reply(ctx: Context<FEDM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv4_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = m4a_lv4_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if m4a_lv4_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        m4a_chat.ceo_marked_fed_lv4_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv4_reply_count += 1;
-        m4a_lv4_reply.is_fed = true;
-
-        msg!("M4A Lv4+ Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
- 
-        Ok(())
-    }
-
-    pub fn unfed_m4a_lv4_reply(ctx: Context<UnFEDM4ALv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let m4a_lv4_reply = &mut ctx.accounts.m4a_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(m4a_lv4_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let m4a_chat = &mut ctx.accounts.m4a_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        m4a_chat.ceo_marked_fed_lv4_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv4_reply_count -= 1;
-        m4a_lv4_reply.is_fed = false;
-
-        msg!("M4A Lv4+ Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_pli_comment(ctx: Context<FEDPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        //Can't set flag to the same state because of the counters
-        require!(pli_comment.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = pli_comment.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if pli_comment.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        pli_chat.ceo_marked_fed_comment_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_comment_count += 1;
-        pli_comment.is_fed = true;
-
-        msg!("PLI Comment Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_pli_comment(ctx: Context<UnFEDPLIComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_comment = &mut ctx.accounts.pli_comment;
-        //Can't set flag to the same state because of the counters
-        require!(pli_comment.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        pli_chat.ceo_marked_fed_comment_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_comment_count -= 1;
-        pli_comment.is_fed = false;
-
-        msg!("PLI Comment Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_pli_reply(ctx: Context<FEDPLIReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_reply.is_fed != true, InvalidOperationError::FlagSameState);
-    
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = pli_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if pli_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        pli_chat.ceo_marked_fed_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_reply_count += 1;
-        pli_reply.is_fed = true;
-
-        msg!("PLI Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_pli_reply(ctx: Context<UnFEDPLIReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_reply = &mut ctx.accounts.pli_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_reply.is_fed != false, InvalidOperationError::FlagSameState);
-    
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        pli_chat.ceo_marked_fed_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_reply_count -= 1;
-        pli_reply.is_fed = false;
-
-        msg!("PLI Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_pli_lv3_reply(ctx: Context<FEDPLILv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv3_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = pli_lv3_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if pli_lv3_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        pli_chat.ceo_marked_fed_lv3_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv3_reply_count += 1;
-        pli_lv3_reply.is_fed = true;
-
-        msg!("PLI Lv3 Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_pli_lv3_reply(ctx: Context<UnFEDPLILv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv3_reply = &mut ctx.accounts.pli_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv3_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        pli_chat.ceo_marked_fed_lv3_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv3_reply_count -= 1;
-        pli_lv3_reply.is_fed = false;
-
-        msg!("PLI Lv3 Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_pli_lv4_reply(ctx: Context<FEDPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv4_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = pli_lv4_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if pli_lv4_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        pli_chat.ceo_marked_fed_lv4_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv4_reply_count += 1;
-        pli_lv4_reply.is_fed = true;
-
-        msg!("PLI Lv4+ Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_pli_lv4_reply(ctx: Context<UnFEDPLILv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let pli_lv4_reply = &mut ctx.accounts.pli_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(pli_lv4_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let pli_chat = &mut ctx.accounts.pli_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        pli_chat.ceo_marked_fed_lv4_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv4_reply_count -= 1;
-        pli_lv4_reply.is_fed = false;
-
-        msg!("PLI Lv4+ Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_about_comment(ctx: Context<FEDAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_comment = &mut ctx.accounts.about_comment;
-        //Can't set flag to the same state because of the counters
-        require!(about_comment.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = about_comment.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if about_comment.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        about_chat.ceo_marked_fed_comment_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_comment_count += 1;
-        about_comment.is_fed = true;
-
-        msg!("About Comment Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_about_comment(ctx: Context<UnFEDAboutComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_comment = &mut ctx.accounts.about_comment;
-        //Can't set flag to the same state because of the counters
-        require!(about_comment.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        about_chat.ceo_marked_fed_comment_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_comment_count -= 1;
-        about_comment.is_fed = false;
-
-        msg!("About Comment Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_about_reply(ctx: Context<FEDAboutReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_reply = &mut ctx.accounts.about_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = about_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if about_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        about_chat.ceo_marked_fed_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_reply_count += 1;
-        about_reply.is_fed = true;
-
-        msg!("About Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_about_reply(ctx: Context<UnFEDAboutReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_reply = &mut ctx.accounts.about_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        about_chat.ceo_marked_fed_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_reply_count -= 1;
-        about_reply.is_fed = false;
-
-        msg!("About Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_about_lv3_reply(ctx: Context<FEDAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv3_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = about_lv3_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if about_lv3_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        about_chat.ceo_marked_fed_lv3_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv3_reply_count += 1;
-        about_lv3_reply.is_fed = true;
-
-        msg!("About Lv3 Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_about_lv3_reply(ctx: Context<UnFEDAboutLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv3_reply = &mut ctx.accounts.about_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv3_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        about_chat.ceo_marked_fed_lv3_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv3_reply_count -= 1;
-        about_lv3_reply.is_fed = false;
-
-        msg!("About Lv3 Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_about_lv4_reply(ctx: Context<FEDAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv4_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = about_lv4_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if about_lv4_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        about_chat.ceo_marked_fed_lv4_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv4_reply_count += 1;
-        about_lv4_reply.is_fed = true;
-
-        msg!("About Lv4+ Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_about_lv4_reply(ctx: Context<UnFEDAboutLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let about_lv4_reply = &mut ctx.accounts.about_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(about_lv4_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let about_chat = &mut ctx.accounts.about_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-        
-        about_chat.ceo_marked_fed_lv4_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv4_reply_count -= 1;
-        about_lv4_reply.is_fed = false;
-
-        msg!("About Lv4+ Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_lo_comment(ctx: Context<FEDLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        //Can't set flag to the same state because of the counters
-        require!(lo_comment.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Comment as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = lo_comment.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if lo_comment.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        lo_chat.ceo_marked_fed_comment_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_comment_count += 1;
-        lo_comment.is_fed = true;
-
-        msg!("LO Comment Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_lo_comment(ctx: Context<UnFEDLOComment>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_comment = &mut ctx.accounts.lo_comment;
-        //Can't set flag to the same state because of the counters
-        require!(lo_comment.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        lo_chat.ceo_marked_fed_comment_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_comment_count -= 1;
-        lo_comment.is_fed = false;
-
-        msg!("LO Comment Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_lo_reply(ctx: Context<FEDLOReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = lo_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if lo_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        lo_chat.ceo_marked_fed_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_reply_count += 1;
-        lo_reply.is_fed = true;
-
-        msg!("LO Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_lo_reply(ctx: Context<UnFEDLOReply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_reply = &mut ctx.accounts.lo_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        lo_chat.ceo_marked_fed_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_reply_count -= 1;
-        lo_reply.is_fed = false;
-
-        msg!("LO Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_lo_lv3_reply(ctx: Context<FEDLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv3_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv3Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = lo_lv3_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if lo_lv3_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        lo_chat.ceo_marked_fed_lv3_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv3_reply_count += 1;
-        lo_lv3_reply.is_fed = true;
-
-        msg!("LO Lv3 Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_lo_lv3_reply(ctx: Context<UnFEDLOLv3Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv3_reply = &mut ctx.accounts.lo_lv3_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv3_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-
-        lo_chat.ceo_marked_fed_lv3_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv3_reply_count -= 1;
-        lo_lv3_reply.is_fed = false;
-
-        msg!("LO Lv3 Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn fed_lo_lv4_reply(ctx: Context<FEDLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv4_reply.is_fed != true, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        let fed_record = &mut ctx.accounts.fed_record;
-        
-        fed_stats.federal_agent_post_count += 1;
-        fed_record.id = fed_stats.federal_agent_post_count;
-        fed_record.post_type = PostType::Lv4Reply as u8;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        fed_record.comment_section_name_prefix = comment_section_name_prefix.clone();
-        fed_record.comment_section_name = comment_section_name.clone();
-        fed_record.post_owner_address = post_owner_address;
-        fed_record.chat_account_post_count_index = chat_account_post_count_index;
-        fed_record.post = lo_lv4_reply.msg.clone();
-        fed_record.mark_time = time_stamp;
-
-        if lo_lv4_reply.is_edited == true
-        {
-            fed_record.was_edited_before_mark = true;
-        }
-
-        lo_chat.ceo_marked_fed_lv4_reply_count += 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count += 1;
-        comment_section.ceo_marked_fed_lv4_reply_count += 1;
-        lo_lv4_reply.is_fed = true;
-
-        msg!("LO Lv4+ Reply Marked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn unfed_lo_lv4_reply(ctx: Context<UnFEDLOLv4Reply>,
-        comment_section_name_prefix: String,
-        comment_section_name: String,
-        post_owner_address: Pubkey,
-        _chat_account_post_count_index: u128) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let lo_lv4_reply = &mut ctx.accounts.lo_lv4_reply;
-        //Can't set flag to the same state because of the counters
-        require!(lo_lv4_reply.is_fed != false, InvalidOperationError::FlagSameState);
-
-        let lo_chat = &mut ctx.accounts.lo_chat;
-        let chat_account = &mut ctx.accounts.chat_account;
-        let comment_section = &mut ctx.accounts.comment_section;
-        let fed_stats = &mut ctx.accounts.fed_stats;
-        
-        fed_stats.deleted_federal_agent_post_count += 1;
-        
-        lo_chat.ceo_marked_fed_lv4_reply_count -= 1;
-        chat_account.ceo_marked_fed_comment_and_reply_count -= 1;
-        comment_section.ceo_marked_fed_lv4_reply_count -= 1;
-        lo_lv4_reply.is_fed = false;
-
-        msg!("LO Lv4+ Reply Unmarked As Federal Agent By CEO");
-        msg!("For User: {}", post_owner_address.key());
-        msg!("Comment Section Prefix: {}", comment_section_name_prefix);
-        msg!("Comment Section: {}", comment_section_name); 
-            
-        Ok(())
-    }
-
-    pub fn create_poll(ctx: Context<CreatePoll>, poll_name: String) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        //Poll name string must not be longer than 144 characters
-        require!(poll_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
-
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        let poll = &mut ctx.accounts.poll;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        poll.is_active = true;
-        poll.poll_index = poll_stats.poll_count;
-        poll.poll_name = poll_name.clone();
-        poll.unix_creation_time_stamp = time_stamp;
-
-        poll_stats.poll_count += 1;
-
-        msg!("New Poll Created: #{}", poll_stats.poll_count);
-        msg!("Poll Name: {}", poll_name);
-
-        Ok(())
-    }
-
-    pub fn edit_poll(ctx: Context<EditPoll>, _poll_index: u128, poll_name: String) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        //Poll name string must not be longer than 144 characters
-        require!(poll_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
-
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        let poll = &mut ctx.accounts.poll;
-
-        poll.poll_name = poll_name;
-        poll_stats.edited_poll_or_poll_option_count += 1;
-
-        msg!("Edited Poll");
-        msg!("New Poll Name: {}", poll.poll_name);
-
-        Ok(())
-    }
-
-    pub fn set_poll_flag(ctx: Context<SetPollFlag>, _poll_index: u128, is_active: bool) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let poll = &mut ctx.accounts.poll;
-        //Can't set flag to the same state
-        require!(poll.is_active != is_active, InvalidOperationError::FlagSameState);
-
-        let poll_stats = &mut ctx.accounts.poll_stats;
-
-        poll.is_active = is_active;
-        poll_stats.edited_poll_or_poll_option_count += 1;
-        
-        msg!("Updated Poll Active Flag To: {}", is_active);
-        msg!("Poll Name: {}", poll.poll_name);
-
-        Ok(())
-    }
-
-    pub fn create_poll_option(ctx: Context<CreatePollOption>, _poll_index: u128, poll_option_name: String) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        //Poll option name string must not be longer than 144 characters
-        require!(poll_option_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
-
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        let poll = &mut ctx.accounts.poll;
-        let poll_option = &mut ctx.accounts.poll_option;
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        poll_option.is_active = true;
-        poll_option.poll_option_index = poll.option_count;
-        poll_option.poll_option_name = poll_option_name;
-        poll_option.unix_creation_time_stamp = time_stamp;
-
-        poll_stats.option_count += 1;
-        poll.option_count += 1;
-
-        msg!("New Poll Option Created");
-        msg!("Poll Name: {}", poll.poll_name);
-        msg!("Poll Option Name: {}", poll_option.poll_option_name);
-
-        Ok(())
-    }
-
-    pub fn edit_poll_option(ctx: Context<EditPollOption>, _poll_index: u128, _poll_option_index: u8, poll_option_name: String) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        //Poll option name string must not be longer than 144 characters
-        require!(poll_option_name.len() <= MAX_POLL_AND_POLL_OPTION_NAME_LENGTH, InvalidLengthError::PollOrPollOptionNameTooLong);
-
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        let poll = &mut ctx.accounts.poll;
-        let poll_option = &mut ctx.accounts.poll_option;
-
-        poll_stats.edited_poll_or_poll_option_count += 1;
-        poll.edited_poll_option_count += 1;
-        poll_option.poll_option_name = poll_option_name;
-
-        msg!("Edited Poll Option");
-        msg!("Poll: {}", poll.poll_name);
-        msg!("New Poll Option Name: {}", poll_option.poll_option_name);
-
-        Ok(())
-    }
-
-    pub fn set_poll_option_flag(ctx: Context<SetPollOptionFlag>,
-        _poll_index: u128,
-        _poll_option_index: u8,
-        is_active: bool) -> Result<()> 
-    {
-        let ceo = &mut ctx.accounts.ceo;
-        //Only the CEO can call this function
-        require_keys_eq!(ctx.accounts.signer.key(), ceo.address.key(), AuthorizationError::NotCEO);
-
-        let poll_option = &mut ctx.accounts.poll_option;
-        //Can't set flag to the same state
-        require!(poll_option.is_active != is_active, InvalidOperationError::FlagSameState);
-
-        let poll = &mut ctx.accounts.poll;
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        
-        poll_stats.edited_poll_or_poll_option_count += 1;
-        poll.edited_poll_option_count += 1;
-        poll_option.is_active = is_active;
-
-        msg!("Updated Poll Option Active Flag To: {}", is_active);
-        msg!("Poll: {}", poll.poll_name);
-        msg!("Poll Option: {}", poll_option.poll_option_name);
-
-        Ok(())
-    }
-
-    pub fn vote_poll_option(ctx: Context<VotePollOption>, poll_index: u128, poll_option_index: u8, _token_mint_address: Pubkey, vote_amount: i128) -> Result<()> 
-    {
-        //You can not vote a 0 ammount
-        require!(vote_amount != 0, InvalidOperationError::CantVoteZeroAmount);
-
-        let mut is_up_vote = false;
-
-        if vote_amount > 0
-        {
-            is_up_vote = true;
-        }
-
-        let chat_account = &mut ctx.accounts.chat_account;
-        
-        let poll_stats = &mut ctx.accounts.poll_stats;
-        let poll_vote_stats = &mut ctx.accounts.poll_vote_stats;
-        let poll = &mut ctx.accounts.poll;
-        let poll_option = &mut ctx.accounts.poll_option;
-        let poll_vote_record = &mut ctx.accounts.poll_vote_record;
-
-        let time_stamp = Clock::get()?.unix_timestamp as u64;
-
-        if is_up_vote
-        {
-            poll_stats.up_vote_score += vote_amount as u128;
-            poll_vote_stats.up_vote_count += 1;
-            poll.up_vote_score += vote_amount as u128;
-            poll.up_vote_count += 1;
-            poll_option.up_vote_score += vote_amount as u128;
-            poll_option.up_vote_count += 1;
-           
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Up Voted Poll Option");
-            msg!("Poll: {}", poll.poll_name);
-            msg!("Poll Option: {}", poll_option.poll_option_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-        else
-        {
-            poll_stats.down_vote_score += vote_amount.abs() as u128;
-            poll_vote_stats.down_vote_count += 1;
-            poll.down_vote_score += vote_amount.abs() as u128;
-            poll.down_vote_count += 1;
-            poll_option.down_vote_score += vote_amount.abs() as u128;
-            poll_option.down_vote_count += 1;
-
-            msg!("User Address: {}", ctx.accounts.signer.key());
-            msg!("Down Voted Poll Option");
-            msg!("Poll: {}", poll.poll_name);
-            msg!("Poll Option: {}", poll_option.poll_option_name);
-            msg!("Vote Amount: {}", vote_amount);
-        }
-
-        poll_vote_record.vote_amount = vote_amount;
-        poll_vote_record.protocol_record_id = poll_vote_stats.up_vote_count + poll_vote_stats.down_vote_count;
-        poll_vote_record.poll_record_id = poll.up_vote_count + poll.down_vote_count;
-        poll_vote_record.poll_index = poll_index;
-        poll_vote_record.poll_option_index = poll_option_index;
-        poll_vote_record.voter_address = ctx.accounts.signer.key();
-        poll_vote_record.unix_creation_time_stamp = time_stamp;
-
-        chat_account.poll_vote_count += 1;
-
-        let accounts = &ctx.accounts;
-        let treasurer = ctx.accounts.treasurer.clone();
-
-        //Call the helper function to transfer the fee
-        apply_fee(
-            accounts.user_ata.to_account_info(),
-            accounts.treasurer_ata.to_account_info(),
-            accounts.signer.to_account_info(),
-            accounts.token_program.to_account_info(),
-            treasurer,
-            FEE_4CENTS * vote_amount.abs() as u64,
-            accounts.fee_token_entry.decimal_amount
-        )?;
-
-        Ok(())
-    }
-}   
-
-//Derived Accounts
-#[derive(Accounts)]
-pub struct InitializeAdminAccounts<'info> 
-{
-    #[account(
-        init, 
-        payer = signer,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump,
-        space = size_of::<ChatProtocolCEO>() + 8)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        init, 
-        payer = signer,
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump,
-        space = size_of::<ChatProtocolTreasurer>() + 8)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"ideaStats".as_ref()], 
-        bump, 
-        space = size_of::<IdeaStats>() + 8)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"fedStats".as_ref()], 
-        bump, 
-        space = size_of::<FEDStats>() + 8)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct PassOnChatProtocolCEO<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct PassOnChatProtocolTreasurer<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct AddFeeTokenEntry<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FeeTokenEntry>() + 8)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct RemoveFeeTokenEntry<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializeQualityOfLifeAccounts<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"chatAccountStats".as_ref()], 
-        bump, 
-        space = size_of::<ChatAccountStats>() + 8)]
-    pub chat_account_stats: Account<'info, ChatAccountStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"commentSectionStats".as_ref()], 
-        bump, 
-        space = size_of::<CommentSectionStats>() + 8)]
-    pub comment_section_stats: Account<'info, CommentSectionStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pollStats".as_ref()], 
-        bump, 
-        space = size_of::<PollStats>() + 8)]
-    pub poll_stats: Account<'info, PollStats>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializeChatProtocol<'info> 
-{
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"chatProtocol".as_ref()], 
-        bump, 
-        space = size_of::<ChatProtocol>() + 8)]
-    pub chat_protocol: Account<'info, ChatProtocol>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteStats".as_ref()], 
-        bump, 
-        space = size_of::<PostVoteStats>() + 8)]
-    pub post_vote_stats: Account<'info, PostVoteStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"videoVoteStats".as_ref()], 
-        bump, 
-        space = size_of::<VideoVoteStats>() + 8)]
-    pub video_vote_stats: Account<'info, VideoVoteStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pollVoteStats".as_ref()], 
-        bump, 
-        space = size_of::<PollVoteStats>() + 8)]
-    pub poll_vote_stats: Account<'info, PollVoteStats>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializeM4AChat<'info> 
-{
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump, 
-        space = size_of::<M4AChat>() + 8)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializePLIChat<'info> 
-{
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliChat".as_ref()], 
-        bump, 
-        space = size_of::<PLIChat>() + 8)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializeAboutChat<'info> 
-{
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump, 
-        space = size_of::<AboutChat>() + 8)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct InitializeLOChat<'info> 
-{
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loChat".as_ref()], 
-        bump, 
-        space = size_of::<LOChat>() + 8)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-pub struct CreateChatAccount<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"chatAccountStats".as_ref()], 
-        bump)]
-    pub chat_account_stats: Account<'info, ChatAccountStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump, 
-        space = size_of::<ChatAccount>() + CHAT_ACCOUNT_EXTRA_SIZE + 8)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct UpdateUserName<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"chatAccountStats".as_ref()], 
-        bump)]
-    pub chat_account_stats: Account<'info, ChatAccountStats>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(token_mint_address: Pubkey)]
-pub struct SetUseCustomNameFlag<'info>
-{
-    #[account(
-        mut, 
-        seeds = [b"chatAccountStats".as_ref()], 
-        bump)]
-    pub chat_account_stats: Account<'info, ChatAccountStats>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
-pub struct CreateCommentSection<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"commentSectionStats".as_ref()], 
-        bump)]
-    pub comment_section_stats: Account<'info, CommentSectionStats>,
-
-    //Ensures that only someone with a chat account can create a comment section, update: I ended up setting the hasGoodEnding flag here. But you can still include a derived account and not actually do anything with it in the function to make sure it exists
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump, 
-        space = size_of::<CommentSection>() + COMMENT_SECTION_EXTRA_SIZE + 8)]
-    pub comment_section: Account<'info, CommentSection>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
-pub struct SetCommentSectionFlag<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSectionStats".as_ref()], 
-        bump)]
-    pub comment_section_stats: Account<'info, CommentSectionStats>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Account<'info, CommentSection>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
-pub struct CommentSectionVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSectionStats".as_ref()], 
-        bump)]
-    pub comment_section_stats: Account<'info, CommentSectionStats>,
-    
-    #[account(
-        mut,
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut, 
-        seeds = [b"videoVoteStats".as_ref()], 
-        bump)]
-    pub video_vote_stats: Account<'info, VideoVoteStats>,
-
-     #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"videoVoteRecord".as_ref(), signer.key().as_ref(), chat_account.video_vote_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<VideoVoteRecord>() + 8)]
-    pub video_vote_record: Account<'info, VideoVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
-pub struct PostM4AComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<M4AComment>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Box<Account<'info, ChatProtocolCEO>>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToM4AComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub m4a_comment: Box<Account<'info, M4AComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<M4AReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub m4a_reply: Account<'info, M4AReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToM4AReply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub m4a_reply: Box<Account<'info, M4AReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<M4AReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToM4ALv3Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub m4a_lv3_reply: Box<Account<'info, M4ALv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<M4AReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToM4ALv4Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub m4a_lv4_reply: Box<Account<'info, M4ALv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<M4AReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub m4a_lv4_plus_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
-pub struct PostPLIComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<PLIComment>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Box<Account<'info, ChatProtocolCEO>>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToPLIComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub pli_comment: Box<Account<'info, PLIComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<PLIReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToPLIReply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub pli_reply: Box<Account<'info, PLIReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<PLIReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToPLILv3Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub pli_lv3_reply: Box<Account<'info, PLILv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<PLIReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToPLILv4Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub pli_lv4_reply: Box<Account<'info, PLILv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<PLIReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub pli_lv4_plus_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
-pub struct PostAboutComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<AboutComment>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Box<Account<'info, ChatProtocolCEO>>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToAboutComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()],
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub about_comment: Box<Account<'info, AboutComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<AboutReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToAboutReply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub about_reply: Box<Account<'info, AboutReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<AboutReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToAboutLv3Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub about_lv3_reply: Box<Account<'info, AboutLv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<AboutReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToAboutLv4Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub about_lv4_reply: Box<Account<'info, AboutLv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<AboutReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub about_lv4_plus_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
-pub struct PostLOComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<LOComment>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub lo_comment: Account<'info, LOComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Box<Account<'info, ChatProtocolCEO>>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToLOComment<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()],
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub lo_comment: Box<Account<'info, LOComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<LOReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub lo_reply: Account<'info, LOReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToLOReply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub lo_reply: Box<Account<'info, LOReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<LOReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToLOLv3Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub lo_lv3_reply: Box<Account<'info, LOLv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<LOReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct ReplyToLOLv4Reply<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"chatProtocol".as_ref()], 
-        bump)]
-    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub lo_lv4_reply: Box<Account<'info, LOLv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump, 
-        space = size_of::<LOReply>() + COMMENT_REPLY_OR_IDEA_EXTRA_SIZE + 8)]
-    pub lo_lv4_plus_reply: Account<'info, LOLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditM4AComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditM4AReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditM4ALv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditM4ALv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditPLIComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditPLIReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditPLILv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditPLILv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditAboutComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditAboutReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditAboutLv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditAboutLv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditLOComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_comment: Account<'info, LOComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditLOReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_reply: Account<'info, LOReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditLOLv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct EditLOLv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteM4AComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteM4AReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteM4ALv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteM4ALv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeletePLIComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeletePLIReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeletePLILv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeletePLILv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteAboutComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteAboutReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteAboutLv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteAboutLv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteLOComment<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_comment: Account<'info, LOComment>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteLOReply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_reply: Account<'info, LOReply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteLOLv3Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
-pub struct DeleteLOLv4Reply<'info> 
-{
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        signer.key().as_ref()], 
-        bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct M4ACommentVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub m4a_comment: Box<Account<'info, M4AComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), m4a_comment.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = m4a_comment.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct M4AReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub m4a_reply: Box<Account<'info, M4AReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), m4a_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = m4a_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct M4ALv3ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub m4a_lv3_reply: Box<Account<'info, M4ALv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), m4a_lv3_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = m4a_lv3_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct M4ALv4ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Box<Account<'info, M4AChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub m4a_lv4_reply: Box<Account<'info, M4ALv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), m4a_lv4_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = m4a_lv4_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct PLICommentVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub pli_comment: Box<Account<'info, PLIComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), pli_comment.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = pli_comment.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct PLIReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub pli_reply: Box<Account<'info, PLIReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), pli_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = pli_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct PLILv3ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub pli_lv3_reply: Box<Account<'info, PLILv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), pli_lv3_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = pli_lv3_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct PLILv4ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Box<Account<'info, PLIChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub pli_lv4_reply: Box<Account<'info, PLILv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), pli_lv4_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = pli_lv4_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct AboutCommentVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub about_comment: Box<Account<'info, AboutComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), about_comment.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = about_comment.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct AboutReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub about_reply: Box<Account<'info, AboutReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), about_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = about_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct AboutLv3ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub about_lv3_reply: Box<Account<'info, AboutLv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), about_lv3_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = about_lv3_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct AboutLv4ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Box<Account<'info, AboutChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub about_lv4_reply: Box<Account<'info, AboutLv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), about_lv4_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = about_lv4_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct LOCommentVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub lo_comment: Box<Account<'info, LOComment>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), lo_comment.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = lo_comment.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct LOReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub lo_reply: Box<Account<'info, LOReply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), lo_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = lo_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct LOLv3ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub lo_lv3_reply: Box<Account<'info, LOLv3Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), lo_lv3_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = lo_lv3_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    canidate_address: Pubkey,
-    chat_account_post_count_index: u128,
-    token_mint_address: Pubkey)]
-pub struct LOLv4ReplyVote<'info> 
-{
-    #[account(
-        mut,
-        seeds = [b"postVoteStats".as_ref()], 
-        bump)]
-    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Box<Account<'info, LOChat>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), canidate_address.key().as_ref()], 
-        bump)]
-    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
-        bump)]
-    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        canidate_address.key().as_ref()],
-        bump)]
-    pub lo_lv4_reply: Box<Account<'info, LOLv4Reply>>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), lo_lv4_reply.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
-        bump, 
-        space = size_of::<PostVoteRecord>() + 8)]
-    pub post_vote_record: Account<'info, PostVoteRecord>,
-
-    #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
-        bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
-
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Box<Account<'info, TokenAccount>>,
-
-    #[account(
-        seeds = [b"feeTokenEntry".as_ref(),
-        token_mint_address.key().as_ref()], 
-        bump)]
-    pub fee_token_entry: Account<'info, FeeTokenEntry>,
-    
-    #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = lo_lv4_reply.post_owner_address
-    )]
-    pub post_owner_usdc_ata: Account<'info, TokenAccount>,
-
-    pub token_program: Program<'info, Token>,
-    
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarM4AComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarM4AComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarM4AReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarM4AReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarM4ALv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarM4ALv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarM4ALv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarM4ALv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarPLIComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarPLIComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarPLIReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarPLIReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarPLILv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarPLILv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarPLILv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarPLILv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarAboutComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarAboutComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarAboutReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarAboutReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarAboutLv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarAboutLv3Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarAboutLv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarAboutLv4Reply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarLOComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_comment: Account<'info, LOComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarLOComment<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_comment: Account<'info, LOComment>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
-
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarLOReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_reply: Account<'info, LOReply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
+        let accounts = &ctx.accounts;
 
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
+        //Call the helper function to transfer the fee
+        let fee_amount = apply_fee(
+            accounts.user_ata.to_account_info(),
+            accounts.treasury_vault_token_account.to_account_info(),
+            accounts.signer.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.token_mint.to_account_info(),
+            accounts.fee_schedule.poll_vote_fee.checked_mul(vote_count).ok_or(InvalidOperationError::FeeAmountOverflow)?,
+            accounts.fee_token_entry.decimal_amount
+        )?;
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        ctx.accounts.treasury_vault.poll_vote_revenue += fee_amount as u128;
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarLOReply<'info> 
-{
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+        Ok(())
+    }
 
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub fn finalize_poll(ctx: Context<FinalizePoll>, poll_index: u128) -> Result<()> 
+    {
+        let poll = &mut ctx.accounts.poll;
+        let time_stamp = Clock::get()?.unix_timestamp as u64;
 
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+        //A poll can only be finalized once
+        require!(poll.is_finalized == false, InvalidOperationError::PollFinalized);
 
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+        //Polls without a closing time never close on their own
+        require!(poll.closes_at != 0 && time_stamp >= poll.closes_at, InvalidOperationError::PollStillOpen);
 
-    #[account(
-        mut,
-        seeds = [b"loReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_reply: Account<'info, LOReply>,
+        poll.is_finalized = true;
+        poll.is_active = false;
+        poll.unix_finalized_time_stamp = time_stamp;
 
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
+        msg!("Poll Finalized");
+        msg!("Finalized By User: {}", ctx.accounts.signer.key());
+        msg!("Poll Name: {}", poll.poll_name);
+        msg!("Up Vote Score: {}, Down Vote Score: {}", poll.up_vote_score, poll.down_vote_score);
+        msg!("Up Vote Count: {}, Down Vote Count: {}", poll.up_vote_count, poll.down_vote_count);
 
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
+        emit!(PollFinalized
+        {
+            poll_index,
+            up_vote_score: poll.up_vote_score,
+            down_vote_score: poll.down_vote_score,
+            up_vote_count: poll.up_vote_count,
+            down_vote_count: poll.down_vote_count,
+            unix_finalized_time_stamp: time_stamp
+        });
 
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        Ok(())
+    }
+}   
 
+//Derived Accounts
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarLOLv3Reply<'info> 
+pub struct InitializeAdminAccounts<'info> 
 {
     #[account(
+        init, 
+        payer = signer,
         seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
+        bump,
+        space = size_of::<ChatProtocolCEO>() + 8)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
+        init, 
+        payer = signer,
+        seeds = [b"chatProtocolTreasurer".as_ref()],
+        bump,
+        space = size_of::<ChatProtocolTreasurer>() + 8)]
+    pub treasurer: Account<'info, ChatProtocolTreasurer>,
 
     #[account(
-        mut,
+        init, 
+        payer = signer, 
         seeds = [b"ideaStats".as_ref()], 
-        bump)]
+        bump, 
+        space = size_of::<IdeaStats>() + 8)]
     pub idea_stats: Account<'info, IdeaStats>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"fedStats".as_ref()], 
         bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
+        space = size_of::<FEDStats>() + 8)]
+    pub fed_stats: Account<'info, FEDStats>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16664,61 +3507,27 @@ pub struct StarLOLv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarLOLv3Reply<'info> 
+pub struct PassOnChatProtocolCEO<'info> 
 {
     #[account(
+        mut,
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
-    #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+pub struct PassOnChatProtocolTreasurer<'info> 
+{
     #[account(
         mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"chatProtocolTreasurer".as_ref()],
         bump)]
-    pub idea: Account<'info, Idea>,
+    pub treasurer: Account<'info, ChatProtocolTreasurer>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16726,11 +3535,8 @@ pub struct UnstarLOLv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct StarLOLv4Reply<'info> 
+#[instruction(token_mint_address: Pubkey)]
+pub struct AddFeeTokenEntry<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16738,50 +3544,38 @@ pub struct StarLOLv4Reply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
+        init, 
+        payer = signer, 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump, 
+        space = size_of::<FeeTokenEntry>() + 8)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
 
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    #[account(address = token_mint_address)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
 
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct RemoveFeeTokenEntry<'info> 
+{
     #[account(
-        mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
+    pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
         mut,
-        seeds = [b"ideaStats".as_ref()], 
+        close = signer,
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
         bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<Idea>() + IDEA_EXTRA_SIZE + 8)]
-    pub idea: Account<'info, Idea>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16789,11 +3583,8 @@ pub struct StarLOLv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnstarLOLv4Reply<'info> 
+#[instruction(token_mint_address: Pubkey)]
+pub struct CreateTreasuryVault<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16801,49 +3592,66 @@ pub struct UnstarLOLv4Reply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
+        init, 
+        payer = signer, 
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
+        bump, 
+        space = size_of::<TreasuryVault>() + 8)]
+    pub treasury_vault: Account<'info, TreasuryVault>,
 
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+        init, 
+        payer = signer, 
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
+        bump, 
+        token::mint = token_mint,
+        token::authority = treasury_vault,
+        token::token_program = token_program)]
+    pub treasury_vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_mint_address)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+#[instruction(token_mint_address: Pubkey)]
+pub struct WithdrawTreasury<'info> 
+{
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"chatProtocolTreasurer".as_ref()],
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub treasurer: Account<'info, ChatProtocolTreasurer>,
 
     #[account(
         mut,
-        seeds = [b"loLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
+    pub treasury_vault: Account<'info, TreasuryVault>,
 
     #[account(
         mut,
-        seeds = [b"ideaStats".as_ref()], 
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
+    pub treasury_vault_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
+        associated_token::mint = token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub treasurer_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(address = token_mint_address)]
+    pub token_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16851,11 +3659,8 @@ pub struct UnstarLOLv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct SetIdeaImplementedFlag<'info> 
+#[instruction(moderator_address: Pubkey)]
+pub struct GrantModerator<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16863,20 +3668,12 @@ pub struct SetIdeaImplementedFlag<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub idea: Account<'info, Idea>,
+        init, 
+        payer = signer, 
+        seeds = [b"moderator".as_ref(), moderator_address.key().as_ref()], 
+        bump, 
+        space = size_of::<Moderator>() + 8)]
+    pub moderator: Account<'info, Moderator>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16884,11 +3681,8 @@ pub struct SetIdeaImplementedFlag<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UpdateIdea<'info> 
+#[instruction(moderator_address: Pubkey)]
+pub struct RevokeModerator<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16897,19 +3691,10 @@ pub struct UpdateIdea<'info>
 
     #[account(
         mut,
-        seeds = [b"ideaStats".as_ref()], 
-        bump)]
-    pub idea_stats: Account<'info, IdeaStats>,
-
-    #[account(
-        mut,
-        seeds = [b"idea".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        close = signer,
+        seeds = [b"moderator".as_ref(), moderator_address.key().as_ref()], 
         bump)]
-    pub idea: Account<'info, Idea>,
+    pub moderator: Account<'info, Moderator>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16917,11 +3702,7 @@ pub struct UpdateIdea<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDM4AComment<'info> 
+pub struct InitializeQualityOfLifeAccounts<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16929,50 +3710,44 @@ pub struct FEDM4AComment<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+        init, 
+        payer = signer, 
+        seeds = [b"chatAccountStats".as_ref()], 
+        bump, 
+        space = size_of::<ChatAccountStats>() + 8)]
+    pub chat_account_stats: Account<'info, ChatAccountStats>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+        init, 
+        payer = signer, 
+        seeds = [b"commentSectionStats".as_ref()], 
+        bump, 
+        space = size_of::<CommentSectionStats>() + 8)]
+    pub comment_section_stats: Account<'info, CommentSectionStats>,
 
     #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
+        init, 
+        payer = signer, 
+        seeds = [b"pollStats".as_ref()], 
+        bump, 
+        space = size_of::<PollStats>() + 8)]
+    pub poll_stats: Account<'info, PollStats>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+        init, 
+        payer = signer, 
+        seeds = [b"feeSchedule".as_ref()], 
+        bump, 
+        space = size_of::<FeeSchedule>() + 8)]
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"reputationConfig".as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = size_of::<ReputationConfig>() + 8)]
+    pub reputation_config: Account<'info, ReputationConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -16980,11 +3755,7 @@ pub struct FEDM4AComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDM4AComment<'info> 
+pub struct UpdateFeeSchedule<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -16993,48 +3764,9 @@ pub struct UnFEDM4AComment<'info>
 
     #[account(
         mut, 
-        seeds = [b"m4aChat".as_ref()], 
-        bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_comment: Account<'info, M4AComment>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"feeSchedule".as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17042,11 +3774,7 @@ pub struct UnFEDM4AComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDM4AReply<'info> 
+pub struct UpdateReputationConfig<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -17055,49 +3783,76 @@ pub struct FEDM4AReply<'info>
 
     #[account(
         mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        seeds = [b"reputationConfig".as_ref()], 
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub reputation_config: Account<'info, ReputationConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> 
+{
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
         mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"protocolConfig".as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+pub struct InitializeChatProtocol<'info> 
+{
     #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
+        init, 
+        payer = signer, 
+        seeds = [b"chatProtocol".as_ref()], 
+        bump, 
+        space = size_of::<ChatProtocol>() + 8)]
+    pub chat_protocol: Account<'info, ChatProtocol>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+        init, 
+        payer = signer, 
+        seeds = [b"protocolConfig".as_ref()], 
+        bump, 
+        space = size_of::<ProtocolConfig>() + 8)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"postVoteStats".as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = size_of::<PostVoteStats>() + 8)]
+    pub post_vote_stats: Account<'info, PostVoteStats>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"videoVoteStats".as_ref()], 
+        bump, 
+        space = size_of::<VideoVoteStats>() + 8)]
+    pub video_vote_stats: Account<'info, VideoVoteStats>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"pollVoteStats".as_ref()], 
+        bump, 
+        space = size_of::<PollVoteStats>() + 8)]
+    pub poll_vote_stats: Account<'info, PollVoteStats>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17105,11 +3860,8 @@ pub struct FEDM4AReply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDM4AReply<'info> 
+#[instruction(chat_name: String)]
+pub struct CreateChat<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -17117,49 +3869,65 @@ pub struct UnFEDM4AReply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        mut,
+        seeds = [b"chatProtocol".as_ref()], 
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub chat_protocol: Account<'info, ChatProtocol>,
 
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        init, 
+        payer = signer, 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
+        bump, 
+        space = 8 + Chat::INIT_SPACE + chat_name.len())]
+    pub chat: Account<'info, Chat>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(chat_name: String)]
+pub struct UpdateChatSettings<'info> 
+{
+    #[account(
+        seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
         mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub chat: Account<'info, Chat>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+pub struct CreateChatAccount<'info> 
+{
     #[account(
-        mut,
-        seeds = [b"m4aReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        mut, 
+        seeds = [b"chatAccountStats".as_ref()], 
         bump)]
-    pub m4a_reply: Account<'info, M4AReply>,
+    pub chat_account_stats: Account<'info, ChatAccountStats>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+        init, 
+        payer = signer, 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
+        bump, 
+        space = 8 + ChatAccount::INIT_SPACE)]
+    pub chat_account: Account<'info, ChatAccount>,
 
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17167,62 +3935,64 @@ pub struct UnFEDM4AReply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDM4ALv3Reply<'info> 
+#[instruction(token_mint_address: Pubkey, user_name: String)]
+pub struct UpdateUserName<'info> 
 {
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
     #[account(
         mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        seeds = [b"chatAccountStats".as_ref()], 
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub chat_account_stats: Account<'info, ChatAccountStats>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        realloc = 8 + ChatAccount::INIT_SPACE + user_name.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        seeds = [b"feeSchedule".as_ref()],
+        bump)]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17230,124 +4000,96 @@ pub struct FEDM4ALv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDM4ALv3Reply<'info> 
+#[instruction(token_mint_address: Pubkey)]
+pub struct SetUseCustomNameFlag<'info>
 {
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
     #[account(
         mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        seeds = [b"chatAccountStats".as_ref()], 
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub chat_account_stats: Account<'info, ChatAccountStats>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
     #[account(
         mut,
-        seeds = [b"m4aLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv3_reply: Account<'info, M4ALv3Reply>,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDM4ALv4Reply<'info> 
-{
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        seeds = [b"feeSchedule".as_ref()],
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
 
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    pub token_program: Interface<'info, TokenInterface>,
+    
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
+pub struct CreateCommentSection<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"commentSectionStats".as_ref()], 
         bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
+    pub comment_section_stats: Account<'info, CommentSectionStats>,
 
+    //Ensures that only someone with a chat account can create a comment section, update: I ended up setting the hasGoodEnding flag here. But you can still include a derived account and not actually do anything with it in the function to make sure it exists
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub chat_account: Account<'info, ChatAccount>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = 8 + CommentSection::INIT_SPACE + comment_section_name_prefix.len() + comment_section_name.len())]
+    pub comment_section: Account<'info, CommentSection>,
+
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17355,11 +4097,8 @@ pub struct FEDM4ALv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDM4ALv4Reply<'info> 
+#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
+pub struct SetCommentSectionFlag<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -17367,49 +4106,21 @@ pub struct UnFEDM4ALv4Reply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"m4aChat".as_ref()], 
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
         bump)]
-    pub m4a_chat: Account<'info, M4AChat>,
+    pub moderator: Option<Account<'info, Moderator>>,
 
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        mut, 
+        seeds = [b"commentSectionStats".as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub comment_section_stats: Account<'info, CommentSectionStats>,
 
     #[account(
         mut, 
         seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"m4aLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub m4a_lv4_reply: Account<'info, M4ALv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub comment_section: Account<'info, CommentSection>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17417,11 +4128,8 @@ pub struct UnFEDM4ALv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDPLIComment<'info> 
+#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
+pub struct UpdateCommentSectionSettings<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -17430,15 +4138,23 @@ pub struct FEDPLIComment<'info>
 
     #[account(
         mut, 
-        seeds = [b"pliChat".as_ref()], 
+        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump)]
-    pub pli_chat: Account<'info, PLIChat>,
+    pub comment_section: Box<Account<'info, CommentSection>>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+#[instruction(comment_section_name_prefix: String, comment_section_name: String, moderator_address: Pubkey)]
+pub struct AppointSectionModerator<'info> 
+{
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
         mut, 
@@ -17446,33 +4162,13 @@ pub struct FEDPLIComment<'info>
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"sectionModerator".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref(), moderator_address.key().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = 8 + SectionModerator::INIT_SPACE + comment_section_name_prefix.len() + comment_section_name.len())]
+    pub section_moderator: Account<'info, SectionModerator>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17480,61 +4176,26 @@ pub struct FEDPLIComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDPLIComment<'info> 
+#[instruction(comment_section_name_prefix: String, comment_section_name: String, moderator_address: Pubkey)]
+pub struct RemoveSectionModerator<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
     #[account(
         mut, 
         seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
-    #[account(
-        mut,
-        seeds = [b"pliComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_comment: Account<'info, PLIComment>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
     #[account(
         mut,
         close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"sectionModerator".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref(), moderator_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub section_moderator: Account<'info, SectionModerator>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17542,92 +4203,38 @@ pub struct UnFEDPLIComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDPLIReply<'info> 
+#[instruction(comment_section_name_prefix: String, comment_section_name: String)]
+pub struct TransferCommentSectionOwnership<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
     #[account(
         mut, 
         seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
-    #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
-
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct UnFEDPLIReply<'info> 
+pub struct SetPostSectionFlag<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
     #[account(
         mut, 
         seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
@@ -17635,31 +4242,20 @@ pub struct UnFEDPLIReply<'info>
     pub comment_section: Box<Account<'info, CommentSection>>,
 
     #[account(
-        mut,
-        seeds = [b"pliReply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_reply: Account<'info, PLIReply>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"sectionModerator".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref(), signer.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub section_moderator: Option<Account<'info, SectionModerator>>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        post_owner_address.key().as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub post: Account<'info, Post>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17667,124 +4263,89 @@ pub struct UnFEDPLIReply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDPLILv3Reply<'info> 
+#[instruction(comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey)]
+pub struct CommentSectionVote<'info> 
 {
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
-    #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
-
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
     #[account(
         mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"commentSectionStats".as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
+    pub comment_section_stats: Account<'info, CommentSectionStats>,
+    
     #[account(
         mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
         bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
+    pub comment_section: Box<Account<'info, CommentSection>>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        mut, 
+        seeds = [b"videoVoteStats".as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub video_vote_stats: Account<'info, VideoVoteStats>,
 
-    #[account(
+     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"videoVoteRecord".as_ref(), signer.key().as_ref(), chat_account.video_vote_count.to_le_bytes().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        space = 8 + VideoVoteRecord::INIT_SPACE + comment_section_name_prefix.len() + comment_section_name.len())]
+    pub video_vote_record: Account<'info, VideoVoteRecord>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDPLILv3Reply<'info> 
-{
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+        init_if_needed, 
+        payer = signer, 
+        seeds = [b"voteTally".as_ref(), comment_section.key().as_ref(), signer.key().as_ref()], 
+        bump, 
+        space = 8 + VoteTally::INIT_SPACE)]
+    pub vote_tally: Option<Box<Account<'info, VoteTally>>>,
 
     #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"pliLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"feeSchedule".as_ref()],
         bump)]
-    pub pli_lv3_reply: Account<'info, PLILv3Reply>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    pub token_program: Interface<'info, TokenInterface>,
+    
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17792,26 +4353,24 @@ pub struct UnFEDPLILv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDPLILv4Reply<'info> 
+#[instruction(chat_name: String, comment_section_name_prefix: String, comment_section_name: String, token_mint_address: Pubkey, msg: String)]
+pub struct PostComment<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"chatProtocol".as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
 
     #[account(
         mut, 
-        seeds = [b"pliChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub pli_chat: Account<'info, PLIChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
@@ -17821,95 +4380,65 @@ pub struct FEDPLILv4Reply<'info>
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
-    #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
+        signer.key().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        space = 8 + Post::INIT_SPACE + chat_name.len() + comment_section_name_prefix.len() + comment_section_name.len() + msg.len())]
+    pub post: Account<'info, Post>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDPLILv4Reply<'info> 
-{
     #[account(
+        mut,
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub ceo: Box<Account<'info, ChatProtocolCEO>>,
 
     #[account(
-        mut, 
-        seeds = [b"pliChat".as_ref()], 
-        bump)]
-    pub pli_chat: Account<'info, PLIChat>,
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"pliLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"feeSchedule".as_ref()],
         bump)]
-    pub pli_lv4_reply: Account<'info, PLILv4Reply>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
 
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -17917,124 +4446,103 @@ pub struct UnFEDPLILv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDAboutComment<'info> 
+#[instruction(params: ReplyToPostParams)]
+pub struct ReplyToPost<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"chatProtocol".as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub chat_protocol: Box<Account<'info, ChatProtocol>>,
 
     #[account(
         mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        seeds = [b"chat".as_ref(), params.chat_name.as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub chat_account: Box<Account<'info, ChatAccount>>,
 
     #[account(
         mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"commentSection".as_ref(), params.comment_section_name_prefix.as_ref(), params.comment_section_name.as_ref()], 
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
     #[account(
         mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_comment: Account<'info, AboutComment>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"post".as_ref(),
+        params.chat_name.as_ref(),
+        params.comment_section_name_prefix.as_ref(),
+        params.comment_section_name.as_ref(),
+        params.parent_chat_account_post_count_index.to_le_bytes().as_ref(),
+        params.parent_post_owner_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub parent_post: Box<Account<'info, Post>>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"post".as_ref(),
+        params.chat_name.as_ref(),
+        params.comment_section_name_prefix.as_ref(),
+        params.comment_section_name.as_ref(),
+        chat_account.comment_and_reply_count.to_le_bytes().as_ref(),
+        signer.key().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        space = 8 + Post::INIT_SPACE + params.chat_name.len() + params.comment_section_name_prefix.len() + params.comment_section_name.len() + params.msg.len())]
+    pub post: Account<'info, Post>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDAboutComment<'info> 
-{
     #[account(
+        mut,
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
-        bump)]
-    pub about_chat: Account<'info, AboutChat>,
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"treasuryVault".as_ref(), params.token_mint_address.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), params.token_mint_address.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"aboutComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"feeSchedule".as_ref()],
         bump)]
-    pub about_comment: Account<'info, AboutComment>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"feeTokenEntry".as_ref(),
+        params.token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    pub token_program: Interface<'info, TokenInterface>,
+    
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18042,26 +4550,18 @@ pub struct UnFEDAboutComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDAboutReply<'info> 
+#[instruction(chat_name: String, comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey, msg: String)]
+pub struct EditPost<'info> 
 {
-    #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
-        bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
-
     #[account(
         mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
@@ -18073,58 +4573,91 @@ pub struct FEDAboutReply<'info>
 
     #[account(
         mut,
-        seeds = [b"aboutReply".as_ref(),
+        realloc = 8 + Post::INIT_SPACE + chat_name.len() + comment_section_name_prefix.len() + comment_section_name.len() + msg.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_reply: Account<'info, AboutReply>,
-
-    #[account(
-        mut,
-        seeds = [b"fedStats".as_ref()], 
+        signer.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub post: Account<'info, Post>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"postRevision".as_ref(), post.key().as_ref(), post.revision_count.to_le_bytes().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        space = 8 + PostRevision::INIT_SPACE + post.msg.len())]
+    pub post_revision: Account<'info, PostRevision>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDAboutReply<'info> 
-{
     #[account(
+        mut,
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
+        bump)]
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
+        bump)]
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"feeSchedule".as_ref()],
+        bump)]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(chat_name: String, comment_section_name_prefix: String, comment_section_name: String, chat_account_post_count_index: u128, token_mint_address: Pubkey)]
+pub struct DeletePost<'info> 
+{
     #[account(
         mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
@@ -18136,93 +4669,81 @@ pub struct UnFEDAboutReply<'info>
 
     #[account(
         mut,
-        seeds = [b"aboutReply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        signer.key().as_ref()], 
         bump)]
-    pub about_reply: Account<'info, AboutReply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump)]
-    pub fed_record: Account<'info, FEDRecord>,
-
-    #[account(mut)]
-    pub signer: Signer<'info>,
-    pub system_program: Program<'info, System>
-}
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
 
-#[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDAboutLv3Reply<'info> 
-{
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"feeSchedule".as_ref()],
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"feeTokenEntry".as_ref(),
+        token_mint_address.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    pub token_program: Interface<'info, TokenInterface>,
+    
     #[account(
         mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
+        seeds = [b"idea".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        signer.key().as_ref()], 
         bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
+    pub idea: Option<Box<Account<'info, Idea>>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
         seeds = [b"fedRecord".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        signer.key().as_ref()], 
+        bump)]
+    pub fed_record: Option<Box<Account<'info, FEDRecord>>>,
+
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18230,22 +4751,33 @@ pub struct FEDAboutLv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct UnFEDAboutLv3Reply<'info> 
+pub struct ModeratorDeletePost<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        seeds = [b"sectionModerator".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref(), signer.key().as_ref()], 
+        bump)]
+    pub section_moderator: Option<Account<'info, SectionModerator>>,
+
     #[account(
         mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
@@ -18261,30 +4793,39 @@ pub struct UnFEDAboutLv3Reply<'info>
 
     #[account(
         mut,
-        seeds = [b"aboutLv3Reply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()],
         bump)]
-    pub about_lv3_reply: Account<'info, AboutLv3Reply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"idea".as_ref(),
+        comment_section_name_prefix.as_ref(),
+        comment_section_name.as_ref(),
+        chat_account_post_count_index.to_le_bytes().as_ref(),
+        post_owner_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub idea: Option<Box<Account<'info, Idea>>>,
 
     #[account(
         mut,
-        close = signer,
         seeds = [b"fedRecord".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub fed_record: Option<Box<Account<'info, FEDRecord>>>,
+
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18292,26 +4833,50 @@ pub struct UnFEDAboutLv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
-    post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct FEDAboutLv4Reply<'info> 
+pub struct ClosePostRevision<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
+        comment_section_name_prefix.as_ref(),
+        comment_section_name.as_ref(),
+        chat_account_post_count_index.to_le_bytes().as_ref(),
+        signer.key().as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub post: Account<'info, Post>,
 
     #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        mut,
+        close = signer,
+        seeds = [b"postRevision".as_ref(), post.key().as_ref(), post.revision_count.saturating_sub(1).to_le_bytes().as_ref()], 
+        bump)]
+    pub post_revision: Account<'info, PostRevision>,
+
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
 
+#[derive(Accounts)]
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
+    comment_section_name: String,
+    chat_account_post_count_index: u128)]
+pub struct ClosePost<'info> 
+{
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
     pub chat_account: Account<'info, ChatAccount>,
 
@@ -18323,31 +4888,38 @@ pub struct FEDAboutLv4Reply<'info>
 
     #[account(
         mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        signer.key().as_ref()], 
         bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
+    pub post: Account<'info, Post>,
+
+    #[account(
+        init, 
+        payer = signer, 
+        seeds = [b"postTombstone".as_ref(), post.key().as_ref()], 
+        bump, 
+        space = 8 + PostTombstone::INIT_SPACE)]
+    pub post_tombstone: Option<Box<Account<'info, PostTombstone>>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        address = post.parent_post_address)]
+    pub parent_post: Option<Box<Account<'info, Post>>>,
+
+    #[account(
+        mut,
+        seeds = [b"postTombstone".as_ref(), post.parent_post_address.as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub parent_post_tombstone: Option<Box<Account<'info, PostTombstone>>>,
 
     #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18355,61 +4927,51 @@ pub struct FEDAboutLv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
-    post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct UnFEDAboutLv4Reply<'info> 
+pub struct ClosePostTombstone<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub chat_account: Account<'info, ChatAccount>,
 
+    /// CHECK: The closed post's address, its seeds prove the signer wrote the post the tombstone replaced
     #[account(
-        mut, 
-        seeds = [b"aboutChat".as_ref()], 
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
+        comment_section_name_prefix.as_ref(),
+        comment_section_name.as_ref(),
+        chat_account_post_count_index.to_le_bytes().as_ref(),
+        signer.key().as_ref()], 
         bump)]
-    pub about_chat: Account<'info, AboutChat>,
+    pub post: UncheckedAccount<'info>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        close = signer,
+        seeds = [b"postTombstone".as_ref(), post.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub post_tombstone: Box<Account<'info, PostTombstone>>,
 
     #[account(
         mut,
-        seeds = [b"aboutLv4Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
-        bump)]
-    pub about_lv4_reply: Account<'info, AboutLv4Reply>,
+        address = post_tombstone.parent_post_address)]
+    pub parent_post: Option<Box<Account<'info, Post>>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"postTombstone".as_ref(), post_tombstone.parent_post_address.as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub parent_post_tombstone: Option<Box<Account<'info, PostTombstone>>>,
 
     #[account(
-        mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"protocolConfig".as_ref()],
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub protocol_config: Account<'info, ProtocolConfig>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18417,62 +4979,120 @@ pub struct UnFEDAboutLv4Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct FEDLOComment<'info> 
+#[instruction(params: PostVoteParams)]
+pub struct PostVote<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"postVoteStats".as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
 
     #[account(
         mut, 
-        seeds = [b"loChat".as_ref()], 
+        seeds = [b"chat".as_ref(), params.chat_name.as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), params.canidate_address.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
 
     #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        mut,
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
 
     #[account(
-        mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        mut, 
+        seeds = [b"commentSection".as_ref(), params.comment_section_name_prefix.as_ref(), params.comment_section_name.as_ref()], 
         bump)]
-    pub lo_comment: Account<'info, LOComment>,
+    pub comment_section: Box<Account<'info, CommentSection>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"post".as_ref(),
+        params.chat_name.as_ref(),
+        params.comment_section_name_prefix.as_ref(),
+        params.comment_section_name.as_ref(),
+        params.chat_account_post_count_index.to_le_bytes().as_ref(),
+        params.canidate_address.key().as_ref()],
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub post: Box<Account<'info, Post>>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"postVoteRecord".as_ref(), signer.key().as_ref(), post.post_owner_address.key().as_ref(), voter_chat_account.post_vote_casted_count.to_le_bytes().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = size_of::<PostVoteRecord>() + 8)]
+    pub post_vote_record: Account<'info, PostVoteRecord>,
+
+    #[account(
+        init_if_needed, 
+        payer = signer, 
+        seeds = [b"voteTally".as_ref(), post.key().as_ref(), signer.key().as_ref()], 
+        bump, 
+        space = 8 + VoteTally::INIT_SPACE)]
+    pub vote_tally: Option<Box<Account<'info, VoteTally>>>,
+
+    #[account(
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVault".as_ref(), params.token_mint_address.key().as_ref()], 
+        bump)]
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), params.token_mint_address.key().as_ref()], 
+        bump)]
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"feeSchedule".as_ref()],
+        bump)]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        params.token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Account<'info, FeeTokenEntry>,
+    
+    #[account(
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = post.post_owner_address,
+        associated_token::token_program = token_program
+    )]
+    pub post_owner_usdc_ata: InterfaceAccount<'info, TokenAccount>,
+
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18480,61 +5100,111 @@ pub struct FEDLOComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
-    comment_section_name: String,
-    post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDLOComment<'info> 
+#[instruction(params: SetPostVoteParams)]
+pub struct SetPostVote<'info> 
 {
     #[account(
-        seeds = [b"chatProtocolCEO".as_ref()],
+        mut,
+        seeds = [b"postVoteStats".as_ref()], 
+        bump)]
+    pub post_vote_stats: Box<Account<'info, PostVoteStats>>,
+
+    #[account(
+        mut, 
+        seeds = [b"chat".as_ref(), params.chat_name.as_ref()], 
         bump)]
-    pub ceo: Account<'info, ChatProtocolCEO>,
+    pub chat: Box<Account<'info, Chat>>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
+        mut,
+        seeds = [b"chatAccount".as_ref(), params.canidate_address.key().as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub canidate_chat_account: Box<Account<'info, ChatAccount>>,
 
     #[account(
         mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
+        seeds = [b"chatAccount".as_ref(), signer.key().as_ref()], 
         bump)]
-    pub chat_account: Account<'info, ChatAccount>,
+    pub voter_chat_account: Box<Account<'info, ChatAccount>>,
 
     #[account(
         mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"commentSection".as_ref(), params.comment_section_name_prefix.as_ref(), params.comment_section_name.as_ref()], 
         bump)]
     pub comment_section: Box<Account<'info, CommentSection>>,
 
     #[account(
         mut,
-        seeds = [b"loComment".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"post".as_ref(),
+        params.chat_name.as_ref(),
+        params.comment_section_name_prefix.as_ref(),
+        params.comment_section_name.as_ref(),
+        params.chat_account_post_count_index.to_le_bytes().as_ref(),
+        params.canidate_address.key().as_ref()],
         bump)]
-    pub lo_comment: Account<'info, LOComment>,
+    pub post: Box<Account<'info, Post>>,
+
+    #[account(
+        init_if_needed, 
+        payer = signer, 
+        seeds = [b"postVoteBallot".as_ref(), post.key().as_ref(), signer.key().as_ref()], 
+        bump, 
+        space = 8 + PostVoteBallot::INIT_SPACE)]
+    pub post_vote_ballot: Box<Account<'info, PostVoteBallot>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVault".as_ref(), params.token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()], 
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), params.token_mint_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"feeSchedule".as_ref()],
+        bump)]
+    pub fee_schedule: Box<Account<'info, FeeSchedule>>,
+
+    #[account(
+        seeds = [b"feeTokenEntry".as_ref(),
+        params.token_mint_address.key().as_ref()], 
+        bump)]
+    pub fee_token_entry: Box<Account<'info, FeeTokenEntry>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = post.post_owner_address,
+        associated_token::token_program = token_program
+    )]
+    pub post_owner_usdc_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Box<Account<'info, ProtocolConfig>>,
+
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18542,22 +5212,28 @@ pub struct UnFEDLOComment<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct FEDLOReply<'info> 
+pub struct StarPost<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
-        seeds = [b"loChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub chat: Account<'info, Chat>,
 
     #[account(
         mut,
@@ -18573,31 +5249,37 @@ pub struct FEDLOReply<'info>
 
     #[account(
         mut,
-        seeds = [b"loReply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()],
         bump)]
-    pub lo_reply: Account<'info, LOReply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"ideaStats".as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub idea_stats: Account<'info, IdeaStats>,
 
     #[account(
         init, 
         payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
+        seeds = [b"idea".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        space = 8 + Idea::INIT_SPACE + chat_name.len() + comment_section_name_prefix.len() + comment_section_name.len() + post.msg.len())]
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18605,22 +5287,28 @@ pub struct FEDLOReply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct UnFEDLOReply<'info> 
+pub struct UnstarPost<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
-        seeds = [b"loChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub chat: Account<'info, Chat>,
 
     #[account(
         mut,
@@ -18636,30 +5324,36 @@ pub struct UnFEDLOReply<'info>
 
     #[account(
         mut,
-        seeds = [b"loReply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()],
         bump)]
-    pub lo_reply: Account<'info, LOReply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"ideaStats".as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub idea_stats: Account<'info, IdeaStats>,
 
     #[account(
         mut,
         close = signer,
-        seeds = [b"fedRecord".as_ref(),
+        seeds = [b"idea".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub idea: Account<'info, Idea>,
+
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18671,7 +5365,7 @@ pub struct UnFEDLOReply<'info>
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct FEDLOLv3Reply<'info> 
+pub struct SetIdeaImplementedFlag<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -18679,50 +5373,25 @@ pub struct FEDLOLv3Reply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
         bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
+    pub moderator: Option<Account<'info, Moderator>>,
 
     #[account(
         mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"ideaStats".as_ref()], 
         bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
+    pub idea_stats: Account<'info, IdeaStats>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
-        bump)]
-    pub fed_stats: Account<'info, FEDStats>,
-
-    #[account(
-        init, 
-        payer = signer, 
-        seeds = [b"fedRecord".as_ref(),
+        seeds = [b"idea".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
-        bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
-    pub fed_record: Account<'info, FEDRecord>,
+        bump)]
+    pub idea: Account<'info, Idea>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18733,8 +5402,9 @@ pub struct FEDLOLv3Reply<'info>
 #[instruction(comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
-    chat_account_post_count_index: u128)]
-pub struct UnFEDLOLv3Reply<'info> 
+    chat_account_post_count_index: u128,
+    updated_idea: String)]
+pub struct UpdateIdea<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
@@ -18742,49 +5412,28 @@ pub struct UnFEDLOLv3Reply<'info>
     pub ceo: Account<'info, ChatProtocolCEO>,
 
     #[account(
-        mut, 
-        seeds = [b"loChat".as_ref()], 
-        bump)]
-    pub lo_chat: Account<'info, LOChat>,
-
-    #[account(
-        mut,
-        seeds = [b"chatAccount".as_ref(), post_owner_address.key().as_ref()], 
-        bump)]
-    pub chat_account: Account<'info, ChatAccount>,
-
-    #[account(
-        mut, 
-        seeds = [b"commentSection".as_ref(), comment_section_name_prefix.as_ref(), comment_section_name.as_ref()], 
-        bump)]
-    pub comment_section: Box<Account<'info, CommentSection>>,
-
-    #[account(
-        mut,
-        seeds = [b"loLv3Reply".as_ref(),
-        comment_section_name_prefix.as_ref(),
-        comment_section_name.as_ref(),
-        chat_account_post_count_index.to_le_bytes().as_ref(),
-        post_owner_address.key().as_ref()],
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
         bump)]
-    pub lo_lv3_reply: Account<'info, LOLv3Reply>,
+    pub moderator: Option<Account<'info, Moderator>>,
 
     #[account(
         mut,
-        seeds = [b"fedStats".as_ref()], 
+        seeds = [b"ideaStats".as_ref()], 
         bump)]
-    pub fed_stats: Account<'info, FEDStats>,
+    pub idea_stats: Account<'info, IdeaStats>,
 
     #[account(
         mut,
-        close = signer,
-        seeds = [b"fedRecord".as_ref(),
+        realloc = 8 + Idea::INIT_SPACE + idea.chat_name.len() + comment_section_name_prefix.len() + comment_section_name.len() + updated_idea.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
+        seeds = [b"idea".as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
         bump)]
-    pub fed_record: Account<'info, FEDRecord>,
+    pub idea: Account<'info, Idea>,
 
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -18792,22 +5441,28 @@ pub struct UnFEDLOLv3Reply<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct FEDLOLv4Reply<'info> 
+pub struct FEDPost<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
-        seeds = [b"loChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub chat: Account<'info, Chat>,
 
     #[account(
         mut,
@@ -18823,13 +5478,14 @@ pub struct FEDLOLv4Reply<'info>
 
     #[account(
         mut,
-        seeds = [b"loLv4Reply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()],
         bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
@@ -18846,31 +5502,42 @@ pub struct FEDLOLv4Reply<'info>
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()], 
         bump, 
-        space = size_of::<FEDRecord>() + FEDERAL_AGENT_EXTRA_SIZE + 8)]
+        space = 8 + FEDRecord::INIT_SPACE + chat_name.len() + comment_section_name_prefix.len() + comment_section_name.len() + post.msg.len())]
     pub fed_record: Account<'info, FEDRecord>,
 
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
-#[instruction(comment_section_name_prefix: String,
+#[instruction(chat_name: String,
+    comment_section_name_prefix: String,
     comment_section_name: String,
     post_owner_address: Pubkey,
     chat_account_post_count_index: u128)]
-pub struct UnFEDLOLv4Reply<'info> 
+pub struct UnFEDPost<'info> 
 {
     #[account(
         seeds = [b"chatProtocolCEO".as_ref()],
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
-        seeds = [b"loChat".as_ref()], 
+        seeds = [b"chat".as_ref(), chat_name.as_ref()], 
         bump)]
-    pub lo_chat: Account<'info, LOChat>,
+    pub chat: Account<'info, Chat>,
 
     #[account(
         mut,
@@ -18886,13 +5553,14 @@ pub struct UnFEDLOLv4Reply<'info>
 
     #[account(
         mut,
-        seeds = [b"loLv4Reply".as_ref(),
+        seeds = [b"post".as_ref(),
+        chat_name.as_ref(),
         comment_section_name_prefix.as_ref(),
         comment_section_name.as_ref(),
         chat_account_post_count_index.to_le_bytes().as_ref(),
         post_owner_address.key().as_ref()],
         bump)]
-    pub lo_lv4_reply: Account<'info, LOLv4Reply>,
+    pub post: Account<'info, Post>,
 
     #[account(
         mut,
@@ -18911,12 +5579,18 @@ pub struct UnFEDLOLv4Reply<'info>
         bump)]
     pub fed_record: Account<'info, FEDRecord>,
 
+    #[account(
+        seeds = [b"reputationConfig".as_ref()],
+        bump)]
+    pub reputation_config: Box<Account<'info, ReputationConfig>>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
 }
 
 #[derive(Accounts)]
+#[instruction(poll_name: String)]
 pub struct CreatePoll<'info> 
 {
     #[account(
@@ -18924,6 +5598,11 @@ pub struct CreatePoll<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -18935,7 +5614,7 @@ pub struct CreatePoll<'info>
         payer = signer, 
         seeds = [b"poll".as_ref(), poll_stats.poll_count.to_le_bytes().as_ref()], 
         bump, 
-        space = size_of::<Poll>() + POLL_AND_POLL_OPTION_EXTRA_SIZE + 8)]
+        space = 8 + Poll::INIT_SPACE + poll_name.len())]
     pub poll: Account<'info, Poll>,
 
     #[account(mut)]
@@ -18944,7 +5623,7 @@ pub struct CreatePoll<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(poll_index: u128)]
+#[instruction(poll_index: u128, poll_name: String)]
 pub struct EditPoll<'info> 
 {
     #[account(
@@ -18952,6 +5631,11 @@ pub struct EditPoll<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -18960,6 +5644,9 @@ pub struct EditPoll<'info>
 
     #[account(
         mut, 
+        realloc = 8 + Poll::INIT_SPACE + poll_name.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
         seeds = [b"poll".as_ref(), poll_index.to_le_bytes().as_ref()], 
         bump)]
     pub poll: Account<'info, Poll>,
@@ -18978,6 +5665,11 @@ pub struct SetPollFlag<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -18996,7 +5688,7 @@ pub struct SetPollFlag<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(poll_index: u128)]
+#[instruction(poll_index: u128, poll_option_name: String)]
 pub struct CreatePollOption<'info> 
 {
     #[account(
@@ -19004,6 +5696,11 @@ pub struct CreatePollOption<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -19021,7 +5718,7 @@ pub struct CreatePollOption<'info>
         payer = signer, 
         seeds = [b"pollOption".as_ref(), poll_index.to_le_bytes().as_ref(), poll.option_count.to_le_bytes().as_ref(),], 
         bump, 
-        space = size_of::<PollOption>() + POLL_AND_POLL_OPTION_EXTRA_SIZE + 8)]
+        space = 8 + PollOption::INIT_SPACE + poll_option_name.len())]
     pub poll_option: Account<'info, PollOption>,
 
     #[account(mut)]
@@ -19030,7 +5727,7 @@ pub struct CreatePollOption<'info>
 }
 
 #[derive(Accounts)]
-#[instruction(poll_index: u128, poll_option_index: u8)]
+#[instruction(poll_index: u128, poll_option_index: u8, poll_option_name: String)]
 pub struct EditPollOption<'info> 
 {
     #[account(
@@ -19038,6 +5735,11 @@ pub struct EditPollOption<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -19052,6 +5754,9 @@ pub struct EditPollOption<'info>
 
     #[account(
         mut, 
+        realloc = 8 + PollOption::INIT_SPACE + poll_option_name.len(),
+        realloc::payer = signer,
+        realloc::zero = false,
         seeds = [b"pollOption".as_ref(), poll_index.to_le_bytes().as_ref(), poll_option_index.to_le_bytes().as_ref()], 
         bump)]
     pub poll_option: Account<'info, PollOption>,
@@ -19070,6 +5775,48 @@ pub struct SetPollOptionFlag<'info>
         bump)]
     pub ceo: Account<'info, ChatProtocolCEO>,
 
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        mut, 
+        seeds = [b"pollStats".as_ref()], 
+        bump)]
+    pub poll_stats: Account<'info, PollStats>,
+
+    #[account(
+        mut, 
+        seeds = [b"poll".as_ref(), poll_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub poll: Account<'info, Poll>,
+
+    #[account(
+        mut,
+        seeds = [b"pollOption".as_ref(), poll_index.to_le_bytes().as_ref(), poll_option_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub poll_option: Account<'info, PollOption>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(poll_index: u128, poll_option_index: u8)]
+pub struct DeletePollOption<'info> 
+{
+    #[account(
+        seeds = [b"chatProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, ChatProtocolCEO>,
+
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
     #[account(
         mut, 
         seeds = [b"pollStats".as_ref()], 
@@ -19084,6 +5831,7 @@ pub struct SetPollOptionFlag<'info>
 
     #[account(
         mut,
+        close = signer,
         seeds = [b"pollOption".as_ref(), poll_index.to_le_bytes().as_ref(), poll_option_index.to_le_bytes().as_ref()], 
         bump)]
     pub poll_option: Account<'info, PollOption>,
@@ -19093,6 +5841,38 @@ pub struct SetPollOptionFlag<'info>
     pub system_program: Program<'info, System>
 }
 
+#[derive(Accounts)]
+#[instruction(poll_index: u128)]
+pub struct DeletePoll<'info> 
+{
+    #[account(
+        seeds = [b"chatProtocolCEO".as_ref()],
+        bump)]
+    pub ceo: Account<'info, ChatProtocolCEO>,
+
+    #[account(
+        seeds = [b"moderator".as_ref(), signer.key().as_ref()],
+        bump)]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    #[account(
+        mut, 
+        seeds = [b"pollStats".as_ref()], 
+        bump)]
+    pub poll_stats: Account<'info, PollStats>,
+
+    #[account(
+        mut, 
+        close = signer,
+        seeds = [b"poll".as_ref(), poll_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub poll: Account<'info, Poll>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
 #[derive(Accounts)]
 #[instruction(poll_index: u128, poll_option_index: u8, token_mint_address: Pubkey)]
 pub struct VotePollOption<'info> 
@@ -19136,23 +5916,29 @@ pub struct VotePollOption<'info>
     pub poll_vote_record: Account<'info, PollVoteRecord>,
 
     #[account(
-        seeds = [b"chatProtocolTreasurer".as_ref()],
+        mut,
+        associated_token::mint = fee_token_entry.token_mint_address,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
+    )]
+    pub user_ata: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"treasuryVault".as_ref(), token_mint_address.key().as_ref()], 
         bump)]
-    pub treasurer: Account<'info, ChatProtocolTreasurer>,
+    pub treasury_vault: Box<Account<'info, TreasuryVault>>,
 
     #[account(
         mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = signer
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
+        seeds = [b"treasuryVaultTokenAccount".as_ref(), token_mint_address.key().as_ref()], 
+        bump)]
+    pub treasury_vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        associated_token::mint = fee_token_entry.token_mint_address,
-        associated_token::authority = treasurer.address
-    )]
-    pub treasurer_ata: Account<'info, TokenAccount>,
+        seeds = [b"feeSchedule".as_ref()],
+        bump)]
+    pub fee_schedule: Account<'info, FeeSchedule>,
 
     #[account(
         seeds = [b"feeTokenEntry".as_ref(),
@@ -19160,8 +5946,31 @@ pub struct VotePollOption<'info>
         bump)]
     pub fee_token_entry: Account<'info, FeeTokenEntry>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(address = fee_token_entry.token_mint_address)]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     
+    #[account(
+        seeds = [b"protocolConfig".as_ref()],
+        bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    pub system_program: Program<'info, System>
+}
+
+#[derive(Accounts)]
+#[instruction(poll_index: u128)]
+pub struct FinalizePoll<'info> 
+{
+    #[account(
+        mut, 
+        seeds = [b"poll".as_ref(), poll_index.to_le_bytes().as_ref()], 
+        bump)]
+    pub poll: Account<'info, Poll>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
     pub system_program: Program<'info, System>
@@ -19171,13 +5980,15 @@ pub struct VotePollOption<'info>
 #[account]
 pub struct ChatProtocolCEO
 {
-    pub address: Pubkey
+    pub address: Pubkey,
+    pub pending_address: Pubkey //Default address when there's no handover in progress
 }
 
 #[account]
 pub struct ChatProtocolTreasurer
 {
-    pub address: Pubkey
+    pub address: Pubkey,
+    pub pending_address: Pubkey //Default address when there's no handover in progress
 }
 
 #[account]
@@ -19188,562 +5999,286 @@ pub struct FeeTokenEntry
 }
 
 #[account]
-pub struct ChatProtocol
-{
-    pub chat_protocol_initiator_address: Pubkey,
-    pub comment_and_reply_count: u128
-}
-
-#[account]
-pub struct M4AChat
-{
-    pub chat_initiator_address: Pubkey,
-    pub comment_up_vote_count: u128,
-    pub comment_down_vote_count: u128,
-    pub reply_up_vote_count: u128,
-    pub reply_down_vote_count: u128,
-    pub reply_lv3_up_vote_count: u128,
-    pub reply_lv3_down_vote_count: u128,
-    pub reply_lv4_up_vote_count: u128,
-    pub reply_lv4_down_vote_count: u128,
-    pub comment_count: u128,
-    pub reply_count: u128,
-    pub reply_lv3_count: u128,
-    pub reply_lv4_count: u128,
-    pub edited_comment_count: u128,
-    pub edited_reply_count: u128,
-    pub edited_lv3_reply_count: u128,
-    pub edited_lv4_reply_count: u128,
-    pub deleted_comment_count: u128,
-    pub deleted_reply_count: u128,
-    pub deleted_lv3_reply_count: u128,
-    pub deleted_lv4_reply_count: u128,
-    pub ceo_starred_comment_count: u128,
-    pub ceo_starred_reply_count: u128,
-    pub ceo_starred_lv3_reply_count: u128,
-    pub ceo_starred_lv4_reply_count: u128,
-    pub ceo_marked_fed_comment_count: u128,
-    pub ceo_marked_fed_reply_count: u128,
-    pub ceo_marked_fed_lv3_reply_count: u128,
-    pub ceo_marked_fed_lv4_reply_count: u128
-}
-
-#[account]
-pub struct PLIChat
-{
-    pub chat_initiator_address: Pubkey,
-    pub comment_up_vote_count: u128,
-    pub comment_down_vote_count: u128,
-    pub reply_up_vote_count: u128,
-    pub reply_down_vote_count: u128,
-    pub reply_lv3_up_vote_count: u128,
-    pub reply_lv3_down_vote_count: u128,
-    pub reply_lv4_up_vote_count: u128,
-    pub reply_lv4_down_vote_count: u128,
-    pub comment_count: u128,
-    pub reply_count: u128,
-    pub reply_lv3_count: u128,
-    pub reply_lv4_count: u128,
-    pub edited_comment_count: u128,
-    pub edited_reply_count: u128,
-    pub edited_lv3_reply_count: u128,
-    pub edited_lv4_reply_count: u128,
-    pub deleted_comment_count: u128,
-    pub deleted_reply_count: u128,
-    pub deleted_lv3_reply_count: u128,
-    pub deleted_lv4_reply_count: u128,
-    pub ceo_starred_comment_count: u128,
-    pub ceo_starred_reply_count: u128,
-    pub ceo_starred_lv3_reply_count: u128,
-    pub ceo_starred_lv4_reply_count: u128,
-    pub ceo_marked_fed_comment_count: u128,
-    pub ceo_marked_fed_reply_count: u128,
-    pub ceo_marked_fed_lv3_reply_count: u128,
-    pub ceo_marked_fed_lv4_reply_count: u128
-}
-
-#[account]
-pub struct AboutChat
-{
-    pub chat_initiator_address: Pubkey,
-    pub comment_up_vote_count: u128,
-    pub comment_down_vote_count: u128,
-    pub reply_up_vote_count: u128,
-    pub reply_down_vote_count: u128,
-    pub reply_lv3_up_vote_count: u128,
-    pub reply_lv3_down_vote_count: u128,
-    pub reply_lv4_up_vote_count: u128,
-    pub reply_lv4_down_vote_count: u128,
-    pub comment_count: u128,
-    pub reply_count: u128,
-    pub reply_lv3_count: u128,
-    pub reply_lv4_count: u128,
-    pub edited_comment_count: u128,
-    pub edited_reply_count: u128,
-    pub edited_lv3_reply_count: u128,
-    pub edited_lv4_reply_count: u128,
-    pub deleted_comment_count: u128,
-    pub deleted_reply_count: u128,
-    pub deleted_lv3_reply_count: u128,
-    pub deleted_lv4_reply_count: u128,
-    pub ceo_starred_comment_count: u128,
-    pub ceo_starred_reply_count: u128,
-    pub ceo_starred_lv3_reply_count: u128,
-    pub ceo_starred_lv4_reply_count: u128,
-    pub ceo_marked_fed_comment_count: u128,
-    pub ceo_marked_fed_reply_count: u128,
-    pub ceo_marked_fed_lv3_reply_count: u128,
-    pub ceo_marked_fed_lv4_reply_count: u128
-}
-
-#[account]
-pub struct LOChat
-{
-    pub chat_initiator_address: Pubkey,
-    pub comment_up_vote_count: u128,
-    pub comment_down_vote_count: u128,
-    pub reply_up_vote_count: u128,
-    pub reply_down_vote_count: u128,
-    pub reply_lv3_up_vote_count: u128,
-    pub reply_lv3_down_vote_count: u128,
-    pub reply_lv4_up_vote_count: u128,
-    pub reply_lv4_down_vote_count: u128,
-    pub comment_count: u128,
-    pub reply_count: u128,
-    pub reply_lv3_count: u128,
-    pub reply_lv4_count: u128,
-    pub edited_comment_count: u128,
-    pub edited_reply_count: u128,
-    pub edited_lv3_reply_count: u128,
-    pub edited_lv4_reply_count: u128,
-    pub deleted_comment_count: u128,
-    pub deleted_reply_count: u128,
-    pub deleted_lv3_reply_count: u128,
-    pub deleted_lv4_reply_count: u128,
-    pub ceo_starred_comment_count: u128,
-    pub ceo_starred_reply_count: u128,
-    pub ceo_starred_lv3_reply_count: u128,
-    pub ceo_starred_lv4_reply_count: u128,
-    pub ceo_marked_fed_comment_count: u128,
-    pub ceo_marked_fed_reply_count: u128,
-    pub ceo_marked_fed_lv3_reply_count: u128,
-    pub ceo_marked_fed_lv4_reply_count: u128
-}
-
-//Use for helping to know when to refetch all of the chat accounts
-#[account]
-pub struct ChatAccountStats
-{
-    pub chat_account_count: u64,
-    pub set_flag_count: u64,
-    pub updated_name_count: u64
-}
-
-#[account]
-pub struct ChatAccount
-{
-    pub id: u64,
-    pub user_address: Pubkey,
-    pub user_name: String,
-    pub use_custom_name: bool,
-    pub has_had_custom_name: bool,
-    pub has_good_ending: bool,
-    pub poll_vote_count: u128,
-    pub video_vote_count: u128,
-    pub post_vote_casted_count: u128, //This is needed for deriving the account for the vote record. Yes it is just the net sum of up_vote_casted_count and down_vote_casted_count. Couldn't get them to add together in the seeds Q_Q
-    pub received_up_vote_score: u128,
-    pub received_down_vote_score: u128,
-    pub casted_up_vote_score: u128,
-    pub casted_down_vote_score: u128,
-    pub up_vote_received_count: u128,
-    pub down_vote_received_count: u128,
-    pub up_vote_casted_count: u128,
-    pub down_vote_casted_count: u128,
-    pub comment_and_reply_count: u128,
-    pub edited_comment_and_reply_count: u128,
-    pub deleted_comment_and_reply_count: u128,
-    pub ceo_starred_comment_and_reply_count: u128,
-    pub ceo_marked_fed_comment_and_reply_count: u128
-}
-
-#[account]
-pub struct CommentSectionStats
-{
-    pub comment_section_count: u128,
-    pub video_up_vote_count: u128,
-    pub video_down_vote_count: u128,
-    pub toggle_flag_count: u32
-}
-
-#[account]
-pub struct CommentSection
-{
-    pub id: u128,
-    pub is_disabled: bool,
-    pub comment_section_initiator_address: Pubkey,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub video_up_vote_score: u128,
-    pub video_down_vote_score: u128,
-    pub video_up_vote_count: u128,
-    pub video_down_vote_count: u128,
-    pub post_up_vote_score: u128,
-    pub post_down_vote_score: u128,
-    pub post_up_vote_count: u128,
-    pub post_down_vote_count: u128,
-    pub comment_up_vote_score: u128,
-    pub comment_down_vote_score: u128,
-    pub comment_up_vote_count: u128,
-    pub comment_down_vote_count: u128,
-    pub reply_up_vote_score: u128,
-    pub reply_down_vote_score: u128,
-    pub reply_up_vote_count: u128,
-    pub reply_down_vote_count: u128,
-    pub reply_to_reply_up_vote_score: u128,
-    pub reply_to_reply_down_vote_score: u128,
-    pub reply_lv3_up_vote_count: u128,
-    pub reply_lv3_down_vote_count: u128,
-    pub reply_to_lv3_reply_up_vote_score: u128,
-    pub reply_to_lv3_reply_down_vote_score: u128,
-    pub reply_lv4_up_vote_count: u128,
-    pub reply_lv4_down_vote_count: u128,
-    pub comment_and_reply_count: u128,
-    pub comment_count: u128,
-    pub reply_count: u128,
-    pub reply_lv3_count: u128,
-    pub reply_lv4_count: u128,
-    pub edited_comment_count: u128,
-    pub edited_reply_count: u128,
-    pub edited_lv3_reply_count: u128,
-    pub edited_lv4_reply_count: u128,
-    pub deleted_comment_count: u128,
-    pub deleted_reply_count: u128,
-    pub deleted_lv3_reply_count: u128,
-    pub deleted_lv4_reply_count: u128,
-    pub ceo_starred_comment_count: u128,
-    pub ceo_starred_reply_count: u128,
-    pub ceo_starred_lv3_reply_count: u128,
-    pub ceo_starred_lv4_reply_count: u128,
-    pub ceo_marked_fed_comment_count: u128,
-    pub ceo_marked_fed_reply_count: u128,
-    pub ceo_marked_fed_lv3_reply_count: u128,
-    pub ceo_marked_fed_lv4_reply_count: u128
-}
-
-#[account]
-pub struct M4AComment
+#[derive(InitSpace)]
+pub struct SectionModerator
 {
-    pub id: u128,
-    pub protocol_post_count: u128,
+    pub address: Pubkey,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub appointed_by_address: Pubkey,
+    pub unix_creation_time_stamp: u64
 }
 
 #[account]
-pub struct M4AReply
+pub struct Moderator
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub address: Pubkey,
+    pub permissions: u8, //Bitmask of the MODERATOR_PERMISSION_* constants
+    pub unix_creation_time_stamp: u64
 }
 
 #[account]
-pub struct M4ALv3Reply
+pub struct TreasuryVault //All amounts are in the mint's native token amount
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub token_mint_address: Pubkey,
+    pub comment_revenue: u128,
+    pub reply_revenue: u128,
+    pub edit_revenue: u128,
+    pub delete_revenue: u128,
+    pub up_vote_revenue: u128,
+    pub down_vote_revenue: u128,
+    pub name_change_revenue: u128,
+    pub name_flag_toggle_revenue: u128,
+    pub video_vote_revenue: u128,
+    pub poll_vote_revenue: u128,
+    pub withdrawn_amount: u128
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeScheduleParams //Arguments for update_fee_schedule, all fees are in cents
+{
+    pub comment_fee: u64,
+    pub reply_fee: u64,
+    pub edit_fee: u64,
+    pub delete_fee: u64,
+    pub up_vote_fee: u64,
+    pub down_vote_fee: u64,
+    pub author_tip_share: u64,
+    pub name_change_fee: u64,
+    pub name_flag_toggle_fee: u64,
+    pub video_vote_fee: u64,
+    pub poll_vote_fee: u64
 }
 
 #[account]
-pub struct M4ALv4Reply
-{
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+pub struct FeeSchedule //All fees are in cents, vote fees are charged per vote
+{
+    pub comment_fee: u64,
+    pub reply_fee: u64,
+    pub edit_fee: u64,
+    pub delete_fee: u64,
+    pub up_vote_fee: u64,
+    pub down_vote_fee: u64,
+    pub author_tip_share: u64, //Sent to the post owner on each up vote
+    pub name_change_fee: u64,
+    pub name_flag_toggle_fee: u64,
+    pub video_vote_fee: u64,
+    pub poll_vote_fee: u64,
+    pub refund_vote_fees_on_change: bool //Refunds the treasury part of the fee when a changeable vote is lowered or retracted
 }
 
 #[account]
-pub struct PLIComment
+pub struct ReputationConfig //Reputation weights are applied per vote, decay_half_life_seconds of 0 turns decay off
 {
-    pub id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub up_vote_weight: i64,
+    pub down_vote_weight: i64,
+    pub star_weight: i64,
+    pub fed_weight: i64,
+    pub decay_half_life_seconds: u64
 }
 
 #[account]
-pub struct PLIReply
+pub struct ProtocolConfig //Created alongside the ChatProtocol account
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub paused_flags: u8,
+    pub keep_idea_copy_on_delete: bool,
+    pub keep_fed_record_copy_on_delete: bool,
+    pub edit_window_seconds: u64 //0 means posts can be edited forever
 }
 
 #[account]
-pub struct PLILv3Reply
+pub struct ChatProtocol
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub chat_protocol_initiator_address: Pubkey,
+    pub comment_and_reply_count: u128,
+    pub chat_count: u128
 }
 
 #[account]
-pub struct PLILv4Reply
+#[derive(InitSpace)]
+pub struct Chat
 {
     pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub chat_initiator_address: Pubkey,
+    #[max_len(0)]
+    pub chat_name: String,
+    pub max_post_depth: u8,
+    pub use_changeable_votes: bool, //Votes go through one changeable PostVoteBallot per voter and post instead of append only PostVoteRecords
+    pub comment_up_vote_count: u128,
+    pub comment_down_vote_count: u128,
+    pub reply_up_vote_count: u128,
+    pub reply_down_vote_count: u128,
+    pub comment_count: u128,
+    pub reply_count: u128,
+    pub edited_comment_count: u128,
+    pub edited_reply_count: u128,
+    pub deleted_comment_count: u128,
+    pub deleted_reply_count: u128,
+    pub ceo_starred_comment_count: u128,
+    pub ceo_starred_reply_count: u128,
+    pub ceo_marked_fed_comment_count: u128,
+    pub ceo_marked_fed_reply_count: u128
 }
 
+//Use for helping to know when to refetch all of the chat accounts
 #[account]
-pub struct AboutComment
+pub struct ChatAccountStats
 {
-    pub id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub chat_account_count: u64,
+    pub set_flag_count: u64,
+    pub updated_name_count: u64
 }
 
 #[account]
-pub struct AboutReply
+#[derive(InitSpace)]
+pub struct ChatAccount
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub id: u64,
+    pub user_address: Pubkey,
+    #[max_len(0)]
+    pub user_name: String,
+    pub use_custom_name: bool,
+    pub has_had_custom_name: bool,
+    pub has_good_ending: bool,
+    pub poll_vote_count: u128,
+    pub video_vote_count: u128,
+    pub post_vote_casted_count: u128, //This is needed for deriving the account for the vote record. Yes it is just the net sum of up_vote_casted_count and down_vote_casted_count. Couldn't get them to add together in the seeds Q_Q
+    pub received_up_vote_score: u128,
+    pub received_down_vote_score: u128,
+    pub casted_up_vote_score: u128,
+    pub casted_down_vote_score: u128,
+    pub up_vote_received_count: u128,
+    pub down_vote_received_count: u128,
+    pub up_vote_casted_count: u128,
+    pub down_vote_casted_count: u128,
+    pub comment_and_reply_count: u128,
+    pub edited_comment_and_reply_count: u128,
+    pub deleted_comment_and_reply_count: u128,
+    pub closed_comment_and_reply_count: u128,
+    pub ceo_starred_comment_and_reply_count: u128,
+    pub ceo_marked_fed_comment_and_reply_count: u128,
+    pub reputation: i128, //Decays towards 0 over time, only up to date as of reputation_update_time_stamp
+    pub reputation_update_time_stamp: u64, //Moves forward by whole half lives while decay is on
 }
 
 #[account]
-pub struct AboutLv3Reply
+pub struct CommentSectionStats
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub comment_section_count: u128,
+    pub video_up_vote_count: u128,
+    pub video_down_vote_count: u128,
+    pub toggle_flag_count: u32
 }
 
-#[account]
-pub struct AboutLv4Reply
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct TrendingPost
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub post_address: Pubkey,
+    pub hot_score: i128
 }
 
 #[account]
-pub struct LOComment
+#[derive(InitSpace)]
+pub struct CommentSection
 {
     pub id: u128,
-    pub protocol_post_count: u128,
+    pub is_disabled: bool,
+    pub comment_section_initiator_address: Pubkey,
+    pub owner_address: Pubkey,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub section_moderator_count: u32,
+    pub vote_pricing_mode: u8, //Applies to post votes and video votes in this comment section
+    pub trending_posts: [TrendingPost; TRENDING_POST_COUNT], //Sorted by hot score, highest first, unused slots have the default address
+    pub pinned_post_count: u128,
+    pub hidden_post_count: u128,
+    pub video_up_vote_score: u128,
+    pub video_down_vote_score: u128,
+    pub video_up_vote_count: u128,
+    pub video_down_vote_count: u128,
+    pub post_up_vote_score: u128,
+    pub post_down_vote_score: u128,
+    pub post_up_vote_count: u128,
+    pub post_down_vote_count: u128,
+    pub comment_up_vote_score: u128,
+    pub comment_down_vote_score: u128,
+    pub comment_up_vote_count: u128,
+    pub comment_down_vote_count: u128,
+    pub reply_up_vote_score: u128,
+    pub reply_down_vote_score: u128,
+    pub reply_up_vote_count: u128,
+    pub reply_down_vote_count: u128,
+    pub comment_and_reply_count: u128,
+    pub comment_count: u128,
+    pub reply_count: u128,
+    pub edited_comment_count: u128,
+    pub edited_reply_count: u128,
+    pub deleted_comment_count: u128,
+    pub deleted_reply_count: u128,
+    pub ceo_starred_comment_count: u128,
+    pub ceo_starred_reply_count: u128,
+    pub ceo_marked_fed_comment_count: u128,
+    pub ceo_marked_fed_reply_count: u128,
 }
 
 #[account]
-pub struct LOReply
+#[derive(InitSpace)]
+pub struct Post
 {
     pub id: u128,
     pub parent_id: u128,
+    pub parent_post_address: Pubkey, //Default address for comments since they have no parent post
+    pub depth: u8, //0 for comments, each reply is one deeper than the post it replies to
     pub protocol_post_count: u128,
+    #[max_len(0)]
+    pub chat_name: String,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
     pub post_owner_address: Pubkey,
     pub chat_account_post_count_index: u128,
+    #[max_len(0)]
     pub msg: String,
     pub net_vote_score: i128,
+    pub hot_score: i128, //Creation time stamp plus or minus HOT_SCORE_SECONDS_PER_ORDER for each order of magnitude in net_vote_score
     pub unix_creation_time_stamp: u64,
     pub is_edited: bool,
     pub is_deleted: bool,
     pub is_starred: bool,
     pub is_fed: bool,
-    pub reply_count: u32
+    pub is_pinned: bool,
+    pub is_hidden: bool,
+    pub removed_by_address: Pubkey, //Default address unless a moderator removed the post
+    pub removal_reason_code: u8,
+    pub deleted_msg_hash: [u8; 32], //All 0s unless the message hash was kept on delete
+    pub reply_count: u32,
+    pub revision_count: u32 //Counts back down as close_post_revision closes the revisions of a deleted post
 }
 
 #[account]
-pub struct LOLv3Reply
+#[derive(InitSpace)]
+pub struct PostTombstone //What close_post keeps of a deleted post that still has replies, found from the post's address
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
-    pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub parent_post_address: Pubkey, //Default address for comments since they have no parent post
+    pub depth: u8,
+    pub reply_count: u32,
+    pub flags: u8 //TOMBSTONE_FLAG bits
 }
 
 #[account]
-pub struct LOLv4Reply
+#[derive(InitSpace)]
+pub struct PostRevision //Holds the message a post had before the edit with the same revision number
 {
-    pub id: u128,
-    pub parent_id: u128,
-    pub protocol_post_count: u128,
-    pub comment_section_name_prefix: String, 
-    pub comment_section_name: String,
-    pub post_owner_address: Pubkey,
-    pub chat_account_post_count_index: u128,
+    pub post_address: Pubkey,
+    pub revision_number: u32,
+    #[max_len(0)]
     pub msg: String,
-    pub net_vote_score: i128,
-    pub unix_creation_time_stamp: u64,
-    pub is_edited: bool,
-    pub is_deleted: bool,
-    pub is_starred: bool,
-    pub is_fed: bool,
-    pub reply_count: u32
+    pub unix_creation_time_stamp: u64
 }
 
 #[account]
@@ -19755,19 +6290,26 @@ pub struct IdeaStats
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Idea
 {
     pub id: u128,
-    pub post_type: u8, //Needed to know which function to call on the front end
+    pub post_depth: u8,
+    #[max_len(0)]
+    pub chat_name: String,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
     pub post_owner_address: Pubkey,
     pub chat_account_post_count_index: u128,
+    #[max_len(0)]
     pub idea: String,
     pub unix_creation_time_stamp: u64,
     pub implementation_time: u64,
     pub is_implemented: bool,
-    pub is_updated: bool
+    pub is_updated: bool,
+    pub reputation_contribution: i128 //The star weight given to the post owner's reputation when the post was starred
 }
 
 #[account]
@@ -19778,17 +6320,24 @@ pub struct FEDStats
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct FEDRecord
 {
     pub id: u128,
-    pub post_type: u8, //Needed to know which function to call on the front end
+    pub post_depth: u8,
+    #[max_len(0)]
+    pub chat_name: String,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
     pub post_owner_address: Pubkey,
     pub chat_account_post_count_index: u128,
+    #[max_len(0)]
     pub post: String,
     pub mark_time: u64,
-    pub was_edited_before_mark: bool
+    pub was_edited_before_mark: bool,
+    pub reputation_contribution: i128 //The FED weight given to the post owner's reputation when the post was marked
 }
 
 #[account]
@@ -19799,12 +6348,15 @@ pub struct VideoVoteStats
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct VideoVoteRecord
 {
     pub protocol_record_id: u128,
     pub comment_section_record_id: u128,
     pub voter_address: Pubkey,
+    #[max_len(0)]
     pub comment_section_name_prefix: String, 
+    #[max_len(0)]
     pub comment_section_name: String,
     pub vote_amount: i128,
     pub unix_creation_time_stamp: u64
@@ -19817,6 +6369,75 @@ pub struct PostVoteStats
     pub post_down_vote_count: u128
 }
 
+#[account]
+#[derive(InitSpace)]
+pub struct VoteTally //Running vote totals per voter and post or comment section, used to price quadratic votes so only quadratic votes are counted
+{
+    pub target_address: Pubkey,
+    pub voter_address: Pubkey,
+    pub up_vote_total: u64,
+    pub down_vote_total: u64
+}
+
+pub struct AuthorTip //Arguments for send_turd_of_tree, the amount is in cents
+{
+    pub post_owner_address: Pubkey,
+    pub amount: u64,
+    pub decimal_amount: u8
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReplyToPostParams //Arguments for reply_to_post, the parent post is found by its owner and their post count index
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub parent_post_owner_address: Pubkey,
+    pub parent_chat_account_post_count_index: u128,
+    pub token_mint_address: Pubkey,
+    pub msg: String
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PostVoteParams //Arguments for post_vote, a positive vote amount up votes and a negative one down votes
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub canidate_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub token_mint_address: Pubkey,
+    pub vote_amount: i128
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetPostVoteParams //Arguments for set_post_vote, a vote amount of 0 retracts the vote
+{
+    pub chat_name: String,
+    pub comment_section_name_prefix: String,
+    pub comment_section_name: String,
+    pub canidate_address: Pubkey,
+    pub chat_account_post_count_index: u128,
+    pub token_mint_address: Pubkey,
+    pub vote_amount: i128
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PostVoteBallot //One per voter and post in chats with changeable votes, closed when the vote is retracted
+{
+    pub post_address: Pubkey,
+    pub voter_address: Pubkey,
+    pub canidate_address: Pubkey,
+    pub vote_amount: i128,
+    pub up_vote_fees_paid: u64, //In cents, the treasury part of the fees paid for the up votes on the ballot
+    pub down_vote_fees_paid: u64, //In cents, the treasury part of the fees paid for the down votes on the ballot
+    pub reputation_contribution: i128, //The reputation change the vote gave the post owner when it was last set
+    pub change_count: u32,
+    pub unix_creation_time_stamp: u64,
+    pub unix_update_time_stamp: u64
+}
+
 #[account]
 pub struct PostVoteRecord
 {
@@ -19831,32 +6452,44 @@ pub struct PostVoteRecord
 pub struct PollStats
 {
     pub poll_count: u128,
-    pub option_count: u128,
+    pub option_count: u128, //Never decremented, subtract deleted_option_count to get the number of remaining options
+    pub deleted_poll_count: u128,
+    pub deleted_option_count: u128,
     pub edited_poll_or_poll_option_count: u128,
     pub up_vote_score: u128,
     pub down_vote_score: u128
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Poll
 {
     pub is_active: bool,
     pub poll_index: u128,
+    #[max_len(0)]
     pub poll_name: String,
-    pub up_vote_score: u128,
+    pub up_vote_score: u128, //Only counts the remaining options, deleted options' votes are taken off
     pub down_vote_score: u128,
-    pub up_vote_count: u128,
+    pub up_vote_count: u128, //Only counts the remaining options, deleted options' votes are taken off
     pub down_vote_count: u128,
+    pub vote_record_count: u128, //Never decremented since it numbers the poll's vote records
     pub unix_creation_time_stamp: u64,
-    pub option_count: u8,
+    pub opens_at: u64, //0 means the poll opens when it's created
+    pub closes_at: u64, //0 means the poll never closes on its own
+    pub is_finalized: bool, //Set by finalize_poll once the poll closes, the tallies can't change after this
+    pub unix_finalized_time_stamp: u64,
+    pub option_count: u8, //Never decremented since it is the next option index, subtract deleted_option_count to get the number of remaining options
+    pub deleted_option_count: u8, //Option indexes are never reused, so deleted options are tracked separately from option_count
     pub edited_poll_option_count: u128
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct PollOption
 {
     pub is_active: bool,
     pub poll_option_index: u8,
+    #[max_len(0)]
     pub poll_option_name: String,
     pub up_vote_score: u128,
     pub down_vote_score: u128,
@@ -19882,4 +6515,4 @@ pub struct PollVoteRecord
     pub voter_address: Pubkey,
     pub unix_creation_time_stamp: u64,
     pub vote_amount: i128
-}
\ No newline at end of file
+}
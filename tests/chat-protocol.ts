@@ -5,7 +5,7 @@ import { assert } from "chai"
 import { utf8 } from "@coral-xyz/anchor/dist/cjs/utils/bytes/index.js"
 import * as fs from 'fs'
 import bs58 from 'bs58'
-import { PublicKey, Keypair, Transaction } from '@solana/web3.js'
+import { PublicKey, Keypair, Transaction, SystemProgram, TransactionInstruction } from '@solana/web3.js'
 import { Token, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token"
 
 describe("Chat_Protocol", () => 
@@ -17,6 +17,9 @@ describe("Chat_Protocol", () =>
   const publicKey = anchor.AnchorProvider.local().wallet.publicKey
   var usdcMint = undefined
   const usdcTokenDecimalAmount = 6
+  const TOKEN_2022_PROGRAM_ID = new PublicKey("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb")
+  const transferFeeBasisPoints = 100
+  const maximumTransferFee = 10 //Every vote fee is large enough that the 1% transfer fee is capped at this
 
   const textWith444Characters = "Lorem ipsum dolor sit amet, consectetuer adipiscing elit. Aenean commodo ligula eget dolor. Aenean massa. Cum sociis natoque penatibus et magnis dis parturient montes, nascetur ridiculus mus. Donec quam felis, ultricies nec, pellentesque eu, pretium quis, sem. Nulla consequat massa quis enim. Donec pede justo, fringilla vel, aliquet nec, vulputate eget, arcu. In enim justo, rhoncus ut, imperdiet a, venenatis vitae, justo. Nullam dictum feli"
   const textWith144Characters = "Lorem ipsum dolor sit amet, consectetuer adipiscing elit. Aenean commodo ligula eget dolor. Aenean massa. Cum sociis natoque penatibus et magnis"
@@ -25,7 +28,33 @@ describe("Chat_Protocol", () =>
   const textWith27Characters = "Lorem ipsum dolor sit amet,"
   const textWith26Characters = "Lorem ipsum dolor sit amet"
   const notCEOErrorMsg = "Only the CEO can call this function"
-
+  const notCEOOrModeratorErrorMsg = "Only the CEO or a moderator with permission can call this function"
+  const notCEOModeratorOrSectionOwnerErrorMsg = "Only the CEO, a moderator with permission or the comment section owner can call this function"
+  const notPendingCEOErrorMsg = "Only the pending CEO can accept the title"
+  const maxPostDepthReachedErrorMsg = "Can't reply any deeper in this chat"
+  const commentSectionDisabledErrorMsg = "This comment section is disabled"
+  const pollNotActiveErrorMsg = "This poll is not active"
+  const pollOptionNotActiveErrorMsg = "This poll option is not active"
+  const unsupportedFeeTokenDecimalsErrorMsg = "Fee token must have atleast 2 decimals"
+  const pollStillHasOptionsErrorMsg = "Can't delete poll that still has options, please delete remaining options first"
+  const protocolPausedErrorMsg = "This part of the protocol is paused"
+  const starredPostEditLockedErrorMsg = "Starred posts can't be edited"
+  const fedPostEditLockedErrorMsg = "FED posts can't be edited"
+  const editWindowClosedErrorMsg = "The edit window for this post has closed"
+  const changeableVotesOnlyErrorMsg = "This chat uses changeable votes, use set_post_vote instead"
+  const postNotDeletedErrorMsg = "Only deleted posts can be closed"
+  const postHasRevisionsErrorMsg = "Close the post's revisions before closing the post"
+  const tombstoneHasRepliesErrorMsg = "A tombstone can only be closed once it has no replies left"
+  const pollClosedErrorMsg = "This poll has closed"
+  const pollFinalizedErrorMsg = "This poll has been finalized"
+  const deletedErrorMsg = "This post was deleted"
+  const voteAmountTooLargeErrorMsg = "Vote amount is too large"
+  const missingVoteTallyErrorMsg = "Quadratic vote pricing needs the voter's vote tally account"
+
+  const m4aChatName = "M4A"
+  const pliChatName = "PLI"
+  const aboutChatName = "About"
+  const loChatName = "LO"
   //const m4aCommentSectionNamePrefix = "M4A_" + textWith28Characters //Seed String Can't be more than 32 characters
   const m4aCommentSectionNamePrefix = "M4A"
   //const pliCommentSectionNamePrefix = "PLI_" + textWith28Characters
@@ -36,6 +65,7 @@ describe("Chat_Protocol", () =>
   const loCommentSectionNamePrefix = "LO"
   //const commentSectionName = textWith32Characters
   const commentSectionName = "Overview"
+  const maxPostDepth = 4
   const userName = textWith144Characters 
   const comment = textWith444Characters
   const reply = textWith444Characters
@@ -46,6 +76,14 @@ describe("Chat_Protocol", () =>
   const postDownVote = false
   const unStar = false
   const unFED = false
+  const moderatorManagePollsPermission = 8
+  const pauseVotingFlag = 2
+  const removalReasonCode = 1
+  const storeContentHash = true
+  const linearVotePricingMode = 0
+  const quadraticVotePricingMode = 1
+  const quadraticVoteAmount = 2
+  const postTombstoneSize = 46 //8 byte discriminator, 32 byte parent post address, 1 byte depth, 4 byte reply count and 1 byte flags
 
   let successorWallet = anchor.web3.Keypair.generate()
 
@@ -63,7 +101,7 @@ describe("Chat_Protocol", () =>
       testingWalletKeypair, //Payer for the mint creation
       program.provider.publicKey, // Mint authority (who can mint tokens)
       null, //Freeze authority (optional)
-      6, //Decimals for USDC
+      usdcTokenDecimalAmount, //Decimals for USDC
       TOKEN_PROGRAM_ID //SPL Token program ID
     )
 
@@ -83,34 +121,55 @@ describe("Chat_Protocol", () =>
     assert(ceoAccount.address.toBase58() == program.provider.publicKey.toBase58())
   })
 
-  it("Passes on the Chat Protocol CEO Account", async () => 
+  it("Proposes And Accepts A New Chat Protocol CEO", async () => 
   {
     await airDropSol(successorWallet.publicKey)
 
-    await program.methods.passOnChatProtocolCeo(successorWallet.publicKey).rpc()
-    
+    var transferEvent = undefined
+    const listener = program.addEventListener("adminTransferred", (event) => { transferEvent = event })
+
+    await program.methods.proposeChatProtocolCeo(successorWallet.publicKey).rpc()
+
     var ceoAccount = await program.account.chatProtocolCeo.fetch(getChatProtocolCEOAccountPDA())
+    assert(ceoAccount.address.toBase58() == program.provider.publicKey.toBase58())
+    assert(ceoAccount.pendingAddress.toBase58() == successorWallet.publicKey.toBase58())
+
+    await program.methods.acceptChatProtocolCeo().
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+    
+    ceoAccount = await program.account.chatProtocolCeo.fetch(getChatProtocolCEOAccountPDA())
     assert(ceoAccount.address.toBase58() == successorWallet.publicKey.toBase58())
+    assert(ceoAccount.pendingAddress.toBase58() == PublicKey.default.toBase58())
+
+    await sleep(1000)
+    await program.removeEventListener(listener)
+
+    assert(transferEvent.previousAddress.toBase58() == program.provider.publicKey.toBase58())
+    assert(transferEvent.newAddress.toBase58() == successorWallet.publicKey.toBase58())
   })
   
   it("Passes back the Chat Protocol CEO Account", async () => 
   {
-    await program.methods.passOnChatProtocolCeo(program.provider.publicKey).
+    await program.methods.proposeChatProtocolCeo(program.provider.publicKey).
     accounts({signer: successorWallet.publicKey})
     .signers([successorWallet])
     .rpc()
+
+    await program.methods.acceptChatProtocolCeo().rpc()
     
     var ceoAccount = await program.account.chatProtocolCeo.fetch(getChatProtocolCEOAccountPDA())
     assert(ceoAccount.address.toBase58() == program.provider.publicKey.toBase58())
   })
 
-  it("Verifies That Only CEO Can Pass On Account", async () => 
+  it("Verifies That Only CEO Can Propose A New CEO", async () => 
   {
     var errorMessage = ""
 
     try
     {
-      await program.methods.passOnChatProtocolCeo(program.provider.publicKey).
+      await program.methods.proposeChatProtocolCeo(program.provider.publicKey).
       accounts({signer: successorWallet.publicKey})
       .signers([successorWallet])
       .rpc()
@@ -123,20 +182,140 @@ describe("Chat_Protocol", () =>
     assert(errorMessage == notCEOErrorMsg)
   })
 
+  it("Verifies That Only The Pending CEO Can Accept And That The CEO Can Cancel The Proposal", async () => 
+  {
+    await program.methods.proposeChatProtocolCeo(successorWallet.publicKey).rpc()
+
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.acceptChatProtocolCeo().rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == notPendingCEOErrorMsg)
+
+    await program.methods.cancelChatProtocolCeoProposal().rpc()
+
+    var ceoAccount = await program.account.chatProtocolCeo.fetch(getChatProtocolCEOAccountPDA())
+    assert(ceoAccount.address.toBase58() == program.provider.publicKey.toBase58())
+    assert(ceoAccount.pendingAddress.toBase58() == PublicKey.default.toBase58())
+  })
+
   it("Initializes Quailty of Life Accounts", async () => 
   {
     await program.methods.initializeQualityOfLifeAccounts().rpc()
   })
 
+  it("Updates The Fee Schedule Then Sets It Back", async () => 
+  {
+    const [feeSchedulePDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("feeSchedule")], program.programId)
+    const originalFeeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+
+    assert(originalFeeSchedule.commentFee.eq(new anchor.BN(103)))
+
+    await program.methods.updateFeeSchedule
+    ({
+      commentFee: new anchor.BN(50), replyFee: new anchor.BN(2), editFee: new anchor.BN(2), deleteFee: new anchor.BN(2),
+      upVoteFee: new anchor.BN(2), downVoteFee: new anchor.BN(2), authorTipShare: new anchor.BN(1),
+      nameChangeFee: new anchor.BN(50), nameFlagToggleFee: new anchor.BN(2),
+      videoVoteFee: new anchor.BN(2), pollVoteFee: new anchor.BN(2)
+    }).rpc()
+
+    var feeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+    assert(feeSchedule.commentFee.eq(new anchor.BN(50)))
+    assert(feeSchedule.upVoteFee.eq(new anchor.BN(2)))
+
+    await program.methods.updateFeeSchedule
+    ({
+      commentFee: originalFeeSchedule.commentFee, replyFee: originalFeeSchedule.replyFee, editFee: originalFeeSchedule.editFee, deleteFee: originalFeeSchedule.deleteFee,
+      upVoteFee: originalFeeSchedule.upVoteFee, downVoteFee: originalFeeSchedule.downVoteFee, authorTipShare: originalFeeSchedule.authorTipShare,
+      nameChangeFee: originalFeeSchedule.nameChangeFee, nameFlagToggleFee: originalFeeSchedule.nameFlagToggleFee,
+      videoVoteFee: originalFeeSchedule.videoVoteFee, pollVoteFee: originalFeeSchedule.pollVoteFee
+    }).rpc()
+
+    feeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+    assert(feeSchedule.commentFee.eq(originalFeeSchedule.commentFee))
+  })
+
+  it("Updates The Reputation Config Then Sets It Back", async () => 
+  {
+    const [reputationConfigPDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("reputationConfig")], program.programId)
+    const originalReputationConfig = await program.account.reputationConfig.fetch(reputationConfigPDA)
+
+    assert(originalReputationConfig.starWeight.eq(new anchor.BN(100)))
+
+    await program.methods.updateReputationConfig
+    (
+      new anchor.BN(2), new anchor.BN(-2), new anchor.BN(50), new anchor.BN(-50), new anchor.BN(0)
+    ).rpc()
+
+    var reputationConfig = await program.account.reputationConfig.fetch(reputationConfigPDA)
+    assert(reputationConfig.upVoteWeight.eq(new anchor.BN(2)))
+    assert(reputationConfig.decayHalfLifeSeconds.eq(new anchor.BN(0)))
+
+    await program.methods.updateReputationConfig
+    (
+      originalReputationConfig.upVoteWeight, originalReputationConfig.downVoteWeight,
+      originalReputationConfig.starWeight, originalReputationConfig.fedWeight,
+      originalReputationConfig.decayHalfLifeSeconds
+    ).rpc()
+
+    reputationConfig = await program.account.reputationConfig.fetch(reputationConfigPDA)
+    assert(reputationConfig.starWeight.eq(originalReputationConfig.starWeight))
+  })
+
   it("Adds a Fee Token Entry Then Removes It", async () => 
   {
-    await program.methods.addFeeTokenEntry(usdcMint.publicKey, usdcTokenDecimalAmount).rpc()
+    await program.methods.addFeeTokenEntry(usdcMint.publicKey).accounts({tokenMint: usdcMint.publicKey}).rpc()
     await program.methods.removeFeeTokenEntry(usdcMint.publicKey).rpc()
   })
 
+  it("Verifies That A Fee Token With Less Than 2 Decimals Can't Be Added", async () => 
+  {
+    const noDecimalMint = await Token.createMint
+    (
+      program.provider.connection,
+      testingWalletKeypair,
+      program.provider.publicKey,
+      null,
+      0,
+      TOKEN_PROGRAM_ID
+    )
+
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.addFeeTokenEntry(noDecimalMint.publicKey).accounts({tokenMint: noDecimalMint.publicKey}).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == unsupportedFeeTokenDecimalsErrorMsg)
+  })
+
   it("Adds a Fee Token Entry", async () => 
   {
-    await program.methods.addFeeTokenEntry(usdcMint.publicKey, usdcTokenDecimalAmount).rpc()
+    await program.methods.addFeeTokenEntry(usdcMint.publicKey).accounts({tokenMint: usdcMint.publicKey}).rpc()
+
+    const [feeTokenEntryPDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("feeTokenEntry"), usdcMint.publicKey.toBuffer()], program.programId)
+    const feeTokenEntry = await program.account.feeTokenEntry.fetch(feeTokenEntryPDA)
+    assert(feeTokenEntry.decimalAmount == usdcTokenDecimalAmount)
+  })
+
+  it("Creates The Treasury Vault For The Fee Token", async () => 
+  {
+    await program.methods.createTreasuryVault(usdcMint.publicKey).accounts({tokenMint: usdcMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID}).rpc()
+
+    const treasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(usdcMint.publicKey))
+    assert(treasuryVault.tokenMintAddress.toBase58() == usdcMint.publicKey.toBase58())
   })
 
   it("Initializes Chat Protocol", async () => 
@@ -151,23 +330,30 @@ describe("Chat_Protocol", () =>
 
   it("Updates User Name", async () => 
   {
-    await program.methods.updateUserName(usdcMint.publicKey, userName).rpc()
+    const chatAccountPDA = getChatAccountPDA(program.provider.publicKey)
+    const initialAccountSize = (await program.provider.connection.getAccountInfo(chatAccountPDA)).data.length
+
+    await program.methods.updateUserName(usdcMint.publicKey, userName).accounts(feeTokenAccounts()).rpc()
+
+    //Chat account grows by exactly the length of the new user name
+    const updatedAccountSize = (await program.provider.connection.getAccountInfo(chatAccountPDA)).data.length
+    assert(updatedAccountSize == initialAccountSize + userName.length)
   })
 
   it("Set Use Custom Name Flag False", async () => 
   {
-    await program.methods.setUseCustomNameFlag(usdcMint.publicKey, false).rpc()
+    await program.methods.setUseCustomNameFlag(usdcMint.publicKey, false).accounts(feeTokenAccounts()).rpc()
   })
 
   it("Set Use Custom Name Flag True", async () => 
   {
-    await program.methods.setUseCustomNameFlag(usdcMint.publicKey, true).rpc()
+    await program.methods.setUseCustomNameFlag(usdcMint.publicKey, true).accounts(feeTokenAccounts()).rpc()
   })
 
   it("Creates Poll & Poll Option, Edits Poll & Poll Option, Votes On Poll Option, And Then Toggles The Poll Option and Poll Active Flags", async () => 
   {
     //Create poll and poll option
-    await program.methods.createPoll(textWith144Characters).rpc()
+    await program.methods.createPoll(textWith144Characters, new anchor.BN(0), new anchor.BN(0)).rpc()
     await program.methods.createPollOption(new anchor.BN(0), textWith144Characters).rpc()
 
     var poll = await program.account.poll.fetch(getPollPDA(0))
@@ -177,7 +363,7 @@ describe("Chat_Protocol", () =>
     assert(pollOption.pollOptionName == textWith144Characters)
 
     //Edit poll and poll option
-    await program.methods.editPoll(new anchor.BN(0), "edited test poll").rpc()
+    await program.methods.editPoll(new anchor.BN(0), "edited test poll", new anchor.BN(0), new anchor.BN(0)).rpc()
     await program.methods.editPollOption(new anchor.BN(0), 0, "edited test poll option").rpc()
 
     poll = await program.account.poll.fetch(getPollPDA(0))
@@ -187,7 +373,7 @@ describe("Chat_Protocol", () =>
     assert(pollOption.pollOptionName == "edited test poll option")
 
     //Vote poll option
-    await program.methods.votePollOption(new anchor.BN(0), 0, usdcMint.publicKey, new anchor.BN(100)).rpc()
+    await program.methods.votePollOption(new anchor.BN(0), 0, usdcMint.publicKey, new anchor.BN(100)).accounts(feeTokenAccounts()).rpc()
 
     pollOption = await program.account.pollOption.fetch(getPollOptionPDA(0, 0))
     assert(pollOption.upVoteScore.eq(new anchor.BN(100)))
@@ -199,13 +385,42 @@ describe("Chat_Protocol", () =>
     assert(poll.isActive)
     assert(pollOption.isActive)
 
-    await program.methods.setPollFlag(new anchor.BN(0), false).rpc()
     await program.methods.setPollOptionFlag(new anchor.BN(0), 0, false).rpc()
-    poll = await program.account.poll.fetch(getPollPDA(0))
     pollOption = await program.account.pollOption.fetch(getPollOptionPDA(0, 0))
-    assert(!poll.isActive)
     assert(!pollOption.isActive)
 
+    //Can't vote on an inactive poll option
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.votePollOption(new anchor.BN(0), 0, usdcMint.publicKey, new anchor.BN(100)).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollOptionNotActiveErrorMsg)
+
+    await program.methods.setPollFlag(new anchor.BN(0), false).rpc()
+    poll = await program.account.poll.fetch(getPollPDA(0))
+    assert(!poll.isActive)
+
+    //Can't vote on an inactive poll
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.votePollOption(new anchor.BN(0), 0, usdcMint.publicKey, new anchor.BN(100)).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollNotActiveErrorMsg)
+
     await program.methods.setPollFlag(new anchor.BN(0), true).rpc()
     await program.methods.setPollOptionFlag(new anchor.BN(0), 0, true).rpc()
     poll = await program.account.poll.fetch(getPollPDA(0))
@@ -214,9 +429,187 @@ describe("Chat_Protocol", () =>
     assert(pollOption.isActive)
   })
 
-  it("Initializes M4A Chat", async () => 
+  it("Creates Poll & Poll Option, Verifies That Poll Can't Be Deleted While It Has Options, And Then Deletes The Poll Option And Poll", async () => 
+  {
+    await program.methods.createPoll("poll to delete", new anchor.BN(0), new anchor.BN(0)).rpc()
+    await program.methods.createPollOption(new anchor.BN(1), "poll option to delete").rpc()
+
+    //Can't delete poll that still has options
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.deletePoll(new anchor.BN(1)).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollStillHasOptionsErrorMsg)
+
+    //Deleted option's votes come off the poll's and the poll stats' tallies
+    const pollStatsBeforeVote = await program.account.pollStats.fetch(getPollStatsPDA())
+
+    await program.methods.votePollOption(new anchor.BN(1), 0, usdcMint.publicKey, new anchor.BN(100)).accounts(feeTokenAccounts()).rpc()
+
+    var poll = await program.account.poll.fetch(getPollPDA(1))
+    var pollStats = await program.account.pollStats.fetch(getPollStatsPDA())
+    assert(poll.upVoteScore.eq(new anchor.BN(100)))
+    assert(poll.upVoteCount.eq(new anchor.BN(1)))
+    assert(pollStats.upVoteScore.eq(pollStatsBeforeVote.upVoteScore.add(new anchor.BN(100))))
+
+    await program.methods.deletePollOption(new anchor.BN(1), 0).rpc()
+
+    poll = await program.account.poll.fetch(getPollPDA(1))
+    pollStats = await program.account.pollStats.fetch(getPollStatsPDA())
+    var pollOption = await program.account.pollOption.fetchNullable(getPollOptionPDA(1, 0))
+    assert(poll.deletedOptionCount == 1)
+    assert(poll.upVoteScore.eq(new anchor.BN(0)))
+    assert(poll.upVoteCount.eq(new anchor.BN(0)))
+    assert(poll.voteRecordCount.eq(new anchor.BN(1)))
+    assert(pollStats.upVoteScore.eq(pollStatsBeforeVote.upVoteScore))
+    assert(pollStats.downVoteScore.eq(pollStatsBeforeVote.downVoteScore))
+    assert(pollStats.deletedOptionCount.eq(pollStatsBeforeVote.deletedOptionCount.add(new anchor.BN(1))))
+    assert(pollOption == null)
+
+    await program.methods.deletePoll(new anchor.BN(1)).rpc()
+
+    var deletedPoll = await program.account.poll.fetchNullable(getPollPDA(1))
+    pollStats = await program.account.pollStats.fetch(getPollStatsPDA())
+    assert(deletedPoll == null)
+    assert(pollStats.deletedPollCount.eq(new anchor.BN(1)))
+    assert(pollStats.deletedOptionCount.eq(new anchor.BN(1)))
+  })
+
+  it("Creates A Poll That Has Already Closed, Verifies That It Can't Be Voted On, And Then Finalizes It", async () => 
+  {
+    await program.methods.createPoll("closed poll", new anchor.BN(0), new anchor.BN(1)).rpc()
+    await program.methods.createPollOption(new anchor.BN(2), "closed poll option").rpc()
+
+    //Can't vote on a closed poll
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.votePollOption(new anchor.BN(2), 0, usdcMint.publicKey, new anchor.BN(100)).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollClosedErrorMsg)
+
+    //Anyone can finalize a closed poll
+    var finalizedEvent = undefined
+    const listener = program.addEventListener("pollFinalized", (event) => { finalizedEvent = event })
+
+    await program.methods.finalizePoll(new anchor.BN(2)).
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    await sleep(1000)
+    await program.removeEventListener(listener)
+
+    var poll = await program.account.poll.fetch(getPollPDA(2))
+    assert(poll.isFinalized)
+    assert(!poll.isActive)
+    assert(poll.upVoteCount.eq(new anchor.BN(0)))
+
+    //The event carries the final scores
+    assert(finalizedEvent.pollIndex.eq(new anchor.BN(2)))
+    assert(finalizedEvent.upVoteScore.eq(poll.upVoteScore))
+    assert(finalizedEvent.downVoteScore.eq(poll.downVoteScore))
+    assert(finalizedEvent.unixFinalizedTimeStamp.eq(poll.unixFinalizedTimeStamp))
+
+    //Can't reopen a finalized poll
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.editPoll(new anchor.BN(2), "closed poll", new anchor.BN(0), new anchor.BN(0)).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollFinalizedErrorMsg)
+
+    //Can't delete a finalized poll's options
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.deletePollOption(new anchor.BN(2), 0).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollFinalizedErrorMsg)
+
+    //Can't delete a finalized poll
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.deletePoll(new anchor.BN(2)).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == pollFinalizedErrorMsg)
+  })
+
+  it("Grants A Moderator Poll Permissions, Moderator Edits A Poll, And Then Revokes The Moderator", async () => 
+  {
+    const moderatorPDA = getModeratorPDA(successorWallet.publicKey)
+
+    await program.methods.grantModerator(successorWallet.publicKey, moderatorManagePollsPermission).rpc()
+
+    var moderator = await program.account.moderator.fetch(moderatorPDA)
+    assert(moderator.permissions == moderatorManagePollsPermission)
+
+    await program.methods.editPoll(new anchor.BN(0), "moderator edited poll", new anchor.BN(0), new anchor.BN(0)).
+    accounts({signer: successorWallet.publicKey, moderator: moderatorPDA})
+    .signers([successorWallet])
+    .rpc()
+
+    var poll = await program.account.poll.fetch(getPollPDA(0))
+    assert(poll.pollName == "moderator edited poll")
+
+    await program.methods.revokeModerator(successorWallet.publicKey).rpc()
+
+    var revokedModerator = await program.account.moderator.fetchNullable(moderatorPDA)
+    assert(revokedModerator == null)
+
+    //Revoked moderator can't edit polls anymore
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.editPoll(new anchor.BN(0), "edited test poll", new anchor.BN(0), new anchor.BN(0)).
+      accounts({signer: successorWallet.publicKey})
+      .signers([successorWallet])
+      .rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == notCEOOrModeratorErrorMsg)
+  })
+
+  it("Creates M4A Chat", async () => 
   {
-    await program.methods.initializeM4AChat().rpc()
+    await program.methods.createChat(m4aChatName, maxPostDepth).rpc()
   })
 
   it("Creates A M4A Comment Section", async () => 
@@ -232,6 +625,27 @@ describe("Chat_Protocol", () =>
 
     assert(commentSection.isDisabled == true)
   })
+
+  it("Verifies That A Disabled Comment Section Can't Be Voted On", async () => 
+  {
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.commentSectionVote
+      (
+        m4aCommentSectionNamePrefix, commentSectionName,
+        usdcMint.publicKey, 
+        new anchor.BN(voteAmount)
+      ).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == commentSectionDisabledErrorMsg)
+  })
   
   it("Set Comment Section Disabled Flag False", async () => 
   {
@@ -242,58 +656,158 @@ describe("Chat_Protocol", () =>
     assert(commentSection.isDisabled == false)
   })
 
-  it("Vote For M4A Comment Section Video/Page", async () => 
+  it("Pauses Voting, Verifies That A Comment Section Can't Be Voted On While Paused, And Then Unpauses", async () => 
   {
-    //Up Vote Comment Section Video/Page
-    await program.methods.commentSectionVote
-    (
-      m4aCommentSectionNamePrefix, commentSectionName,
-      usdcMint.publicKey, 
-      new anchor.BN(voteAmount)
-    ).rpc()
+    await program.methods.setProtocolPausedFlags(pauseVotingFlag).rpc()
 
-    var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    var protocolConfig = await program.account.protocolConfig.fetch(getProtocolConfigPDA())
+    assert(protocolConfig.pausedFlags == pauseVotingFlag)
 
-    assert(commentSection.videoUpVoteScore.eq(new anchor.BN(voteAmount)))
-    assert(commentSection.videoUpVoteCount.eq(new anchor.BN(1)))
-    assert(commentSection.videoDownVoteScore.eq(new anchor.BN(0)))
-    assert(commentSection.videoDownVoteCount.eq(new anchor.BN(0)))
+    var errorMessage = ""
 
-    //Down Vote Comment Section Video/Page
-    if(videoDownVote)
+    try
     {
       await program.methods.commentSectionVote
       (
         m4aCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey, 
-        new anchor.BN(negativeVoteAmount)
-      ).rpc()
+        new anchor.BN(voteAmount)
+      ).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
 
-      commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    assert(errorMessage == protocolPausedErrorMsg)
 
-      assert(commentSection.videoUpVoteScore.eq(new anchor.BN(voteAmount)))
-      assert(commentSection.videoUpVoteCount.eq(new anchor.BN(1)))
-      assert(commentSection.videoDownVoteScore.eq(new anchor.BN(voteAmount)))
-      assert(commentSection.videoDownVoteCount.eq(new anchor.BN(1)))
-    }
+    await program.methods.setProtocolPausedFlags(0).rpc()
+
+    protocolConfig = await program.account.protocolConfig.fetch(getProtocolConfigPDA())
+    assert(protocolConfig.pausedFlags == 0)
   })
 
-  it("Posts A M4A Comment, Edits, Up Votes, Down Votes, Stars, Implements Idea, Unimplements Idea, Edits Idea, UnStars, FEDs, UnFEDs, And Then Deletes M4A Comments", async () => 
+  it("Transfers Comment Section Ownership, New Owner Appoints A Section Moderator And Toggles The Disabled Flag, Then CEO Takes Ownership Back", async () => 
   {
-    //Post 100 Comments
+    await program.methods.transferCommentSectionOwnership(m4aCommentSectionNamePrefix, commentSectionName, successorWallet.publicKey).rpc()
+
+    var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.ownerAddress.toBase58() == successorWallet.publicKey.toBase58())
+
+    await program.methods.appointSectionModerator(m4aCommentSectionNamePrefix, commentSectionName, program.provider.publicKey).
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    await program.methods.setCommentSectionFlag(m4aCommentSectionNamePrefix, commentSectionName, true).
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    await program.methods.setCommentSectionFlag(m4aCommentSectionNamePrefix, commentSectionName, false).
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.isDisabled == false)
+    assert(commentSection.sectionModeratorCount == 1)
+
+    await program.methods.removeSectionModerator(m4aCommentSectionNamePrefix, commentSectionName, program.provider.publicKey).
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    //CEO can override the section owner
+    await program.methods.transferCommentSectionOwnership(m4aCommentSectionNamePrefix, commentSectionName, program.provider.publicKey).rpc()
+
+    commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.ownerAddress.toBase58() == program.provider.publicKey.toBase58())
+    assert(commentSection.sectionModeratorCount == 0)
+
+    //Former owner can't toggle the disabled flag anymore
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.setCommentSectionFlag(m4aCommentSectionNamePrefix, commentSectionName, true).
+      accounts({signer: successorWallet.publicKey})
+      .signers([successorWallet])
+      .rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == notCEOModeratorOrSectionOwnerErrorMsg)
+  })
+
+  it("Vote For M4A Comment Section Video/Page", async () => 
+  {
+    //Up Vote Comment Section Video/Page
+    await program.methods.commentSectionVote
+    (
+      m4aCommentSectionNamePrefix, commentSectionName,
+      usdcMint.publicKey, 
+      new anchor.BN(voteAmount)
+    ).accounts(feeTokenAccounts()).rpc()
+
+    var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+
+    assert(commentSection.videoUpVoteScore.eq(new anchor.BN(voteAmount)))
+    assert(commentSection.videoUpVoteCount.eq(new anchor.BN(1)))
+    assert(commentSection.videoDownVoteScore.eq(new anchor.BN(0)))
+    assert(commentSection.videoDownVoteCount.eq(new anchor.BN(0)))
+
+    //Down Vote Comment Section Video/Page
+    if(videoDownVote)
+    {
+      await program.methods.commentSectionVote
+      (
+        m4aCommentSectionNamePrefix, commentSectionName,
+        usdcMint.publicKey, 
+        new anchor.BN(negativeVoteAmount)
+      ).accounts(feeTokenAccounts()).rpc()
+
+      commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+
+      assert(commentSection.videoUpVoteScore.eq(new anchor.BN(voteAmount)))
+      assert(commentSection.videoUpVoteCount.eq(new anchor.BN(1)))
+      assert(commentSection.videoDownVoteScore.eq(new anchor.BN(voteAmount)))
+      assert(commentSection.videoDownVoteCount.eq(new anchor.BN(1)))
+    }
+  })
+
+  it("Treasurer Withdraws From The Treasury Vault", async () => 
+  {
+    var treasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(usdcMint.publicKey))
+    assert(treasuryVault.videoVoteRevenue.gt(new anchor.BN(0)))
+
+    const withdrawAmount = new anchor.BN(1)
+
+    await program.methods.withdrawTreasury(usdcMint.publicKey, withdrawAmount).accounts({tokenMint: usdcMint.publicKey, tokenProgram: TOKEN_PROGRAM_ID}).rpc()
+
+    treasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(usdcMint.publicKey))
+    assert(treasuryVault.withdrawnAmount.eq(withdrawAmount))
+  })
+
+  it("Posts A M4A Comment, Edits, Up Votes, Down Votes, Stars, Implements Idea, Unimplements Idea, Edits Idea, UnStars, FEDs, UnFEDs, And Then Deletes M4A Comments", async () => 
+  {
+    //Post 100 Comments
     for(var i=1; i<=1; i++)
     { 
       console.log("M4A Comment: ", i)
 
       //Post Comment
-      await program.methods.postM4AComment
+      await program.methods.postComment
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey, 
         comment
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      var m4aComments = await program.account.m4AComment.all()
+      var m4aComments = await fetchChatPosts(m4aChatName, 0)
       
       var newM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -304,49 +818,57 @@ describe("Chat_Protocol", () =>
       //Edit Comment
       const editedText = "Edited Comment"
 
-      await program.methods.editM4AComment
+      await program.methods.editPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4AComment[0].account.chatAccountPostCountIndex,
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      m4aComments = await program.account.m4AComment.all()
+      m4aComments = await fetchChatPosts(m4aChatName, 0)
 
       var editedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(editedM4AComment[0].account.msg == editedText)
+      assert(editedM4AComment[0].account.revisionCount == 1)
+
+      const postRevision = await program.account.postRevision.fetch(getPostRevisionPDA(editedM4AComment[0].publicKey, 0))
+      assert(postRevision.msg == comment)
 
       //Up Vote Comment
-      await program.methods.m4ACommentVote
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        newM4AComment[0].account.postOwnerAddress,
-        newM4AComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newM4AComment[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: newM4AComment[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      m4aComments = await program.account.m4AComment.all()
+      m4aComments = await fetchChatPosts(m4aChatName, 0)
 
       var upVotedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(upVotedM4AComment[0].account.netVoteScore.eq(new anchor.BN(voteAmount)))
+      assert(upVotedM4AComment[0].account.hotScore.gt(upVotedM4AComment[0].account.unixCreationTimeStamp))
+
+      var trendingCommentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+      assert(trendingCommentSection.trendingPosts.some((trendingPost: { postAddress: PublicKey }) => trendingPost.postAddress.equals(upVotedM4AComment[0].publicKey)))
 
       //Down Vote Comment
       if(postDownVote)
       {
-        await program.methods.m4ACommentVote
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          newM4AComment[0].account.postOwnerAddress,
-          newM4AComment[0].account.chatAccountPostCountIndex,
-          usdcMint.publicKey, 
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newM4AComment[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: newM4AComment[0].account.chatAccountPostCountIndex,
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        m4aComments = await program.account.m4AComment.all()
+        m4aComments = await fetchChatPosts(m4aChatName, 0)
 
         var downVotedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
         
@@ -354,19 +876,44 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Comment
-      await program.methods.starM4AComment
+      var reputationBeforeStar = (await program.account.chatAccount.fetch(getChatAccountPDA(newM4AComment[0].account.postOwnerAddress))).reputation
+
+      await program.methods.starPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4AComment[0].account.postOwnerAddress,
         newM4AComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      m4aComments = await program.account.m4AComment.all()
+      var reputationAfterStar = (await program.account.chatAccount.fetch(getChatAccountPDA(newM4AComment[0].account.postOwnerAddress))).reputation
+      assert(reputationAfterStar.gt(reputationBeforeStar))
+
+      m4aComments = await fetchChatPosts(m4aChatName, 0)
 
       var starredM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == m4aCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
       assert(starredM4AComment[0].account.isStarred == true)
 
+      //Verify Starred Comment Can't Be Edited
+      var errorMessage = ""
+
+      try
+      {
+        await program.methods.editPost
+        (
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+          newM4AComment[0].account.chatAccountPostCountIndex,
+          usdcMint.publicKey,
+          editedText
+        ).accounts(feeTokenAccounts()).rpc()
+      }
+      catch(error)
+      {
+        errorMessage = error.error.errorMessage
+      }
+
+      assert(errorMessage == starredPostEditLockedErrorMsg)
+
       //Implement Idea
       await program.methods.setIdeaImplementedFlag
       (
@@ -425,14 +972,14 @@ describe("Chat_Protocol", () =>
       //Unstar Comment
       if(unStar)
       {
-        await program.methods.unstarM4AComment
+        await program.methods.unstarPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           newM4AComment[0].account.postOwnerAddress,
           newM4AComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        m4aComments = await program.account.m4AComment.all()
+        m4aComments = await fetchChatPosts(m4aChatName, 0)
 
         var starredM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == m4aCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -440,49 +987,99 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Comment
-      await program.methods.fedM4AComment
+      await program.methods.fedPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4AComment[0].account.postOwnerAddress,
         newM4AComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      m4aComments = await program.account.m4AComment.all()
+      m4aComments = await fetchChatPosts(m4aChatName, 0)
 
       var fedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == m4aCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
       assert(fedM4AComment[0].account.isFed == true)
 
+      //Verify FED Comment Can't Be Edited
+      errorMessage = ""
+
+      try
+      {
+        await program.methods.editPost
+        (
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+          newM4AComment[0].account.chatAccountPostCountIndex,
+          usdcMint.publicKey,
+          editedText
+        ).accounts(feeTokenAccounts()).rpc()
+      }
+      catch(error)
+      {
+        errorMessage = error.error.errorMessage
+      }
+
+      assert(errorMessage == fedPostEditLockedErrorMsg)
+
       //UnFED Comment
       if(unFED)
       {
-        await program.methods.unfedM4AComment
+        await program.methods.unfedPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           newM4AComment[0].account.postOwnerAddress,
           newM4AComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        m4aComments = await program.account.m4AComment.all()
+        m4aComments = await fetchChatPosts(m4aChatName, 0)
 
         var fedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == m4aCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
         assert(fedM4AComment[0].account.isFed == false)
       }
 
+      //Revisions can only be closed once their post is deleted
+      errorMessage = ""
+
+      try
+      {
+        await program.methods.closePostRevision
+        (
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+          newM4AComment[0].account.chatAccountPostCountIndex
+        ).accounts({postRevision: getPostRevisionPDA(newM4AComment[0].publicKey, 0)}).rpc()
+      }
+      catch(error)
+      {
+        errorMessage = error.error.errorMessage
+      }
+
+      assert(errorMessage == postNotDeletedErrorMsg)
+
       //Delete Comment
-      await program.methods.deleteM4AComment
+      await program.methods.deletePost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4AComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(m4aCommentSectionNamePrefix, commentSectionName, newM4AComment[0].account.chatAccountPostCountIndex, program.provider.publicKey)}).rpc()
 
-      m4aComments = await program.account.m4AComment.all()
+      m4aComments = await fetchChatPosts(m4aChatName, 0)
 
       var deletedM4AComment = m4aComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(deletedM4AComment[0].account.isDeleted == true)
+      assert(deletedM4AComment[0].account.msg == "")
+      assert(deletedM4AComment[0].account.deletedMsgHash.some((byte: number) => byte != 0))
+
+      //The revision holding the original message stays open until the author closes it
+      assert(deletedM4AComment[0].account.revisionCount == 1)
+
+      await closePostRevisions(m4aChatName, m4aCommentSectionNamePrefix, commentSectionName, deletedM4AComment[0].account.chatAccountPostCountIndex)
+
+      const revisionClosedM4AComment = await program.account.post.fetch(deletedM4AComment[0].publicKey)
+      assert(revisionClosedM4AComment.revisionCount == 0)
+      assert(await program.provider.connection.getAccountInfo(getPostRevisionPDA(deletedM4AComment[0].publicKey, 0)) == null)
     }
   })
 
@@ -493,21 +1090,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("M4A Reply: ", i)
 
-      var m4aComments= await program.account.m4AComment.all()
+      var m4aComments= await fetchChatPosts(m4aChatName, 0)
 
       //Reply To Comment
-      await program.methods.replyToM4AComment
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        m4aComments[0].account.postOwnerAddress,
-        m4aComments[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: m4aComments[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: m4aComments[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var m4aReplies = await program.account.m4AReply.all()
+      var m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var chatAccountReply = m4aReplies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -518,31 +1115,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editM4AReply
+      await program.methods.editPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      m4aReplies = await program.account.m4AReply.all()
+      m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var editedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
       assert(editedM4AReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.m4AReplyVote
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        m4aReplies[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: m4aReplies[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      m4aReplies = await program.account.m4AReply.all()
+      m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var upVotedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -551,16 +1148,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.m4AReplyVote
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          m4aReplies[0].account.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: m4aReplies[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        m4aReplies = await program.account.m4AReply.all()
+        m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
         var downVotedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -568,14 +1165,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starM4AReply
+      await program.methods.starPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         m4aReplies[0].account.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      m4aReplies = await program.account.m4AReply.all()
+      m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var starredM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -639,15 +1236,15 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarM4AReply
+        await program.methods.unstarPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           m4aReplies[0].account.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
         
 
-        m4aReplies = await program.account.m4AReply.all()
+        m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
         var starredM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -655,14 +1252,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedM4AReply
+      await program.methods.fedPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         m4aReplies[0].account.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      m4aReplies = await program.account.m4AReply.all()
+      m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var fedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -671,14 +1268,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedM4AReply
+        await program.methods.unfedPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           m4aReplies[0].account.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        m4aReplies = await program.account.m4AReply.all()
+        m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
         var fedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -686,14 +1283,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteM4AReply
+      await program.methods.deletePost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
-      ).rpc()
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(m4aCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      m4aReplies = await program.account.m4AReply.all()
+      m4aReplies = await fetchChatPosts(m4aChatName, 1)
 
       var deletedM4AReply = m4aReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4AReply.id))
 
@@ -708,21 +1306,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("M4A Reply To Reply: ", i)
 
-      var m4aReplies= await program.account.m4AReply.all()
+      var m4aReplies= await fetchChatPosts(m4aChatName, 1)
 
       //Reply To Reply
-      await program.methods.replyToM4AReply
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        m4aReplies[0].account.postOwnerAddress,
-        m4aReplies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: m4aReplies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: m4aReplies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      var m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var chatAccountLv3Reply = m4aLv3Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -733,31 +1331,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editM4ALv3Reply
+      await program.methods.editPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var editedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
       assert(editedM4AReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.m4ALv3ReplyVote
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        newM4ALv3Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newM4ALv3Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var upVotedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -766,16 +1364,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.m4ALv3ReplyVote
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          newM4ALv3Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newM4ALv3Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        m4aLv3Replies = await program.account.m4ALv3Reply.all()
+        m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
         var downVotedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -783,14 +1381,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starM4ALv3Reply
+      await program.methods.starPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4ALv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var starredM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -854,14 +1452,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarM4ALv3Reply
+        await program.methods.unstarPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           newM4ALv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         ).rpc()
 
-        m4aLv3Replies = await program.account.m4ALv3Reply.all()
+        m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
         var starredM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -869,14 +1467,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedM4ALv3Reply
+      await program.methods.fedPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4ALv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var fedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -885,14 +1483,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedM4ALv3Reply
+        await program.methods.unfedPost
         (
-          m4aCommentSectionNamePrefix, commentSectionName,
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
           newM4ALv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        m4aLv3Replies = await program.account.m4ALv3Reply.all()
+        m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
         var fedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -900,14 +1498,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteM4ALv3Reply
+      await program.methods.deletePost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(m4aCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       var deletedM4AReply = m4aLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv3Reply.id))
 
@@ -922,21 +1521,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("M4A Reply To Reply To Reply: ", i)
 
-      var m4aLv3Replies = await program.account.m4ALv3Reply.all()
+      var m4aLv3Replies = await fetchChatPosts(m4aChatName, 2)
 
       //Reply To Reply
-      await program.methods.replyToM4ALv3Reply
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        m4aLv3Replies[0].account.postOwnerAddress,
-        m4aLv3Replies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: m4aLv3Replies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: m4aLv3Replies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      var m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
 
       var chatAccountLv4Reply = m4aLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -947,29 +1546,29 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editM4ALv4Reply
+      await program.methods.editPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
 
       var editedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
 
       assert(editedM4AReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.m4ALv4ReplyVote(
-        m4aCommentSectionNamePrefix, commentSectionName,
-        newM4ALv4Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)).rpc()
+      await program.methods.postVote({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newM4ALv4Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)}).accounts(feeTokenAccounts()).rpc()
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
 
       var upVotedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
 
@@ -978,16 +1577,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.m4ALv4ReplyVote
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          newM4ALv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newM4ALv4Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        m4aLv4Replies = await program.account.m4ALv4Reply.all()
+        m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
 
         var downVotedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
 
@@ -995,14 +1594,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starM4ALv4Reply
+      await program.methods.starPost
       (
-        m4aCommentSectionNamePrefix, commentSectionName,
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
         newM4ALv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
 
       var starredM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
 
@@ -1063,93 +1662,694 @@ describe("Chat_Protocol", () =>
       assert(updatedM4ALv4ReplyIdea[0].account.isUpdated == true)
       assert(updatedM4ALv4ReplyIdea[0].account.idea == updatedIdea)
 
-      //Unstar Reply
-      if(unStar)
-      {
-        await program.methods.unstarM4ALv4Reply
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          newM4ALv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
-        ).rpc()
+      //Unstar Reply
+      if(unStar)
+      {
+        await program.methods.unstarPost
+        (
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+          newM4ALv4Reply.postOwnerAddress,
+          chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+        ).rpc()
+
+        m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
+
+        var starredM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+
+        assert(starredM4AReply[0].account.isStarred == false)
+      }
+
+      //FED Reply
+      await program.methods.fedPost
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        newM4ALv4Reply.postOwnerAddress,
+        chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+      ).rpc()
+
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
+
+      var fedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+
+      assert(fedM4AReply[0].account.isFed == true)
+
+      //UnFED Reply
+      if(unFED)
+      {
+        await program.methods.unfedPost
+        (
+          m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+          newM4ALv4Reply.postOwnerAddress,
+          chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+        ).rpc()
+
+        m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
+
+        var fedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+
+        assert(fedM4AReply[0].account.isFed == false)
+      }
+
+      //Delete Reply
+      await program.methods.deletePost
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(m4aCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
+
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 3)
+
+      var deletedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+
+      assert(deletedM4AReply[0].account.isDeleted == true)
+
+      //Reply To Reply
+      const replyToLv4Reply = "Why you delete reply to reply to reply? :0"
+
+      await program.methods.replyToPost
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: deletedM4AReply[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        msg: replyToLv4Reply
+      }).accounts(feeTokenAccounts()).rpc()
+
+      var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+
+      m4aLv4Replies = await fetchChatPosts(m4aChatName, 4)
+
+      var replyToM4ALv4Reply = m4aLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
+
+      assert(replyToM4ALv4Reply[0].account.msg == replyToLv4Reply)
+    }
+  })
+
+  it("Verifies That Replies Can't Go Deeper Than The M4A Chat's Max Post Depth", async () => 
+  {
+    var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.replyToPost
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: program.provider.publicKey,
+        parentChatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == maxPostDepthReachedErrorMsg)
+  })
+
+  it("Verifies That Only A Moderator Can Remove Another User's Post, And Then The CEO Removes The Deepest M4A Reply", async () => 
+  {
+    var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    const postIndex = chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.moderatorDeletePost
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        program.provider.publicKey,
+        postIndex,
+        removalReasonCode
+      ).accounts({signer: successorWallet.publicKey})
+      .signers([successorWallet])
+      .rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == notCEOOrModeratorErrorMsg)
+
+    await program.methods.moderatorDeletePost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      program.provider.publicKey,
+      postIndex,
+      removalReasonCode
+    ).rpc()
+
+    const posts = await fetchChatPosts(m4aChatName, maxPostDepth)
+    const removedPost = posts.find((post: { account: { chatAccountPostCountIndex: anchor.BN }}) => post.account.chatAccountPostCountIndex.eq(postIndex)).account
+
+    assert(removedPost.isDeleted == true)
+    assert(removedPost.msg == "")
+    assert(removedPost.removedByAddress.toBase58() == program.provider.publicKey.toBase58())
+    assert(removedPost.removalReasonCode == removalReasonCode)
+
+    const updatedChatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    assert(updatedChatAccount.deletedCommentAndReplyCount.eq(chatAccount.deletedCommentAndReplyCount.add(new anchor.BN(1))))
+  })
+
+  it("Tombstones The Deleted Parent Of The Deepest M4A Reply, Closes The Removed Deepest M4A Reply, And Then Closes The Tombstone", async () => 
+  {
+    var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    const postIndex = chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+
+    const posts = await fetchChatPosts(m4aChatName, maxPostDepth)
+    const removedPost = posts.find((post: { account: { chatAccountPostCountIndex: anchor.BN }}) => post.account.chatAccountPostCountIndex.eq(postIndex))
+    const parentPostAddress = removedPost.account.parentPostAddress
+    const parentPost = await program.account.post.fetch(parentPostAddress)
+    const postTombstoneAddress = getPostTombstonePDA(parentPostAddress)
+
+    assert(parentPost.isDeleted == true)
+    assert(parentPost.replyCount == 1)
+
+    //The edited parent's revisions have to be closed before the parent
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.closePost
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        parentPost.chatAccountPostCountIndex
+      ).accounts({postTombstone: postTombstoneAddress}).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == postHasRevisionsErrorMsg)
+
+    await closePostRevisions(m4aChatName, m4aCommentSectionNamePrefix, commentSectionName, parentPost.chatAccountPostCountIndex)
+
+    //Deleted posts that still have replies are closed, leaving a tombstone with only what the thread needs
+    await program.methods.closePost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      parentPost.chatAccountPostCountIndex
+    ).accounts({postTombstone: postTombstoneAddress}).rpc()
+
+    const closedParentPost = await program.account.post.fetchNullable(parentPostAddress)
+    assert(closedParentPost == null)
+
+    const tombstone = await program.account.postTombstone.fetch(postTombstoneAddress)
+    const tombstoneAccountInfo = await program.provider.connection.getAccountInfo(postTombstoneAddress)
+    const tombstoneRent = await program.provider.connection.getMinimumBalanceForRentExemption(postTombstoneSize)
+
+    assert(tombstone.parentPostAddress.toBase58() == parentPost.parentPostAddress.toBase58())
+    assert(tombstone.depth == parentPost.depth)
+    assert(tombstone.replyCount == parentPost.replyCount)
+    assert(tombstone.flags == 0)
+    assert(tombstoneAccountInfo.data.length == postTombstoneSize)
+    assert(tombstoneAccountInfo.lamports == tombstoneRent)
+
+    //Can't close a tombstone that still has replies
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.closePostTombstone
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        parentPost.chatAccountPostCountIndex
+      ).accounts({parentPost: parentPost.parentPostAddress}).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == tombstoneHasRepliesErrorMsg)
+
+    await closePostRevisions(m4aChatName, m4aCommentSectionNamePrefix, commentSectionName, postIndex)
+
+    await program.methods.closePost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      postIndex
+    ).accounts({parentPostTombstone: postTombstoneAddress}).rpc()
+
+    const closedPost = await program.account.post.fetchNullable(removedPost.publicKey)
+    assert(closedPost == null)
+
+    const updatedTombstone = await program.account.postTombstone.fetch(postTombstoneAddress)
+    assert(updatedTombstone.replyCount == tombstone.replyCount - 1)
+
+    chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    assert(chatAccount.closedCommentAndReplyCount.eq(new anchor.BN(1)))
+
+    //The tombstone has no replies left, so it can be closed for good
+    await program.methods.closePostTombstone
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      parentPost.chatAccountPostCountIndex
+    ).accounts({parentPost: parentPost.parentPostAddress}).rpc()
+
+    const closedTombstone = await program.account.postTombstone.fetchNullable(postTombstoneAddress)
+    assert(closedTombstone == null)
+
+    chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    assert(chatAccount.closedCommentAndReplyCount.eq(new anchor.BN(2)))
+  })
+
+  it("Switches The M4A Chat To Changeable Votes, Sets And Changes A Vote On A New M4A Comment, Deletes It, Retracts The Vote, And Then Switches Back", async () => 
+  {
+    await program.methods.setChatVoteMode(m4aChatName, true).rpc()
+
+    await program.methods.postComment
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      usdcMint.publicKey, 
+      comment
+    ).accounts(feeTokenAccounts()).rpc()
+
+    var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    const postIndex = chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.postVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: program.provider.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == changeableVotesOnlyErrorMsg)
+
+    //Votes are charged per vote, so a vote amount that doesn't fit in a u64 is rejected
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.setPostVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: program.provider.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(2).pow(new anchor.BN(64)).neg()
+      }).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == voteAmountTooLargeErrorMsg)
+
+    const [feeSchedulePDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("feeSchedule")], program.programId)
+    const feeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+
+    //Set and then change the vote
+    const voteAmounts = [voteAmount, negativeVoteAmount]
+
+    for(const amount of voteAmounts)
+    {
+      await program.methods.setPostVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: program.provider.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(amount)
+      }).accounts(feeTokenAccounts()).rpc()
+
+      const posts = await fetchChatPosts(m4aChatName, 0)
+      const votedPost = posts.find((post: { account: { chatAccountPostCountIndex: anchor.BN }}) => post.account.chatAccountPostCountIndex.eq(postIndex))
+
+      assert(votedPost.account.netVoteScore.eq(new anchor.BN(amount)))
+
+      //The ballot keeps what was paid for the votes on it so changing the vote refunds what was paid
+      const ballot = await program.account.postVoteBallot.fetch(getPostVoteBallotPDA(votedPost.publicKey, program.provider.publicKey))
+
+      assert(ballot.upVoteFeesPaid.eq(amount > 0 ? feeSchedule.upVoteFee.mul(new anchor.BN(amount)) : new anchor.BN(0)))
+      assert(ballot.downVoteFeesPaid.eq(amount < 0 ? feeSchedule.downVoteFee.mul(new anchor.BN(-amount)) : new anchor.BN(0)))
+    }
+
+    await program.methods.deletePost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      postIndex,
+      usdcMint.publicKey,
+      storeContentHash
+    ).accounts(feeTokenAccounts()).rpc()
+
+    //A deleted post can't get new votes
+    errorMessage = ""
+
+    try
+    {
+      await program.methods.setPostVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: program.provider.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == deletedErrorMsg)
+
+    //But the vote on it can still be retracted
+    await program.methods.setPostVote
+    ({
+      chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+      canidateAddress: program.provider.publicKey,
+      chatAccountPostCountIndex: postIndex,
+      tokenMintAddress: usdcMint.publicKey,
+      voteAmount: new anchor.BN(0)
+    }).accounts(feeTokenAccounts()).rpc()
+
+    const posts = await fetchChatPosts(m4aChatName, 0)
+    const votedPost = posts.find((post: { account: { chatAccountPostCountIndex: anchor.BN }}) => post.account.chatAccountPostCountIndex.eq(postIndex))
+    const postVoteBallot = await program.account.postVoteBallot.fetchNullable(getPostVoteBallotPDA(votedPost.publicKey, program.provider.publicKey))
+
+    assert(votedPost.account.netVoteScore.eq(new anchor.BN(0)))
+    assert(postVoteBallot == null)
+
+    const commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(m4aCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.trendingPosts.every((trendingPost: { postAddress: PublicKey }) => !trendingPost.postAddress.equals(votedPost.publicKey)))
+
+    await program.methods.setChatVoteMode(m4aChatName, false).rpc()
+  })
+
+  it("Sets A 1 Second Edit Window, Verifies That A New M4A Comment Can't Be Edited After It Closes, And Then Turns The Edit Window Off", async () => 
+  {
+    await program.methods.setEditWindow(new anchor.BN(1)).rpc()
+
+    await program.methods.postComment
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      usdcMint.publicKey, 
+      comment
+    ).accounts(feeTokenAccounts()).rpc()
+
+    var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+    const postIndex = chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+
+    await sleep(3000) // Sleep for 3 seconds so the edit window closes
+
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.editPost
+      (
+        m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+        postIndex,
+        usdcMint.publicKey,
+        "Edited Comment"
+      ).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == editWindowClosedErrorMsg)
+
+    await program.methods.setEditWindow(new anchor.BN(0)).rpc()
+  })
+
+  it("Votes On Another User's M4A Comment And Checks Their Reputation, Then Stars And Unstars It With A 1 Second Half Life To Check That Reputation Decays", async () => 
+  {
+    const [reputationConfigPDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("reputationConfig")], program.programId)
+    const originalReputationConfig = await program.account.reputationConfig.fetch(reputationConfigPDA)
+
+    //Give the successor wallet USDC and a chat account so it can post
+    const successorWalletATA = await deriveWalletATA(successorWallet.publicKey, usdcMint.publicKey)
+    await createATAForWallet(successorWallet, usdcMint.publicKey, successorWalletATA)
+    await mintUSDCToWallet(usdcMint.publicKey, successorWalletATA)
+
+    await program.methods.createChatAccount().
+    accounts({signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    await program.methods.postComment
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      usdcMint.publicKey, 
+      comment
+    ).accounts({...feeTokenAccounts(), signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
+
+    const successorChatAccountPDA = getChatAccountPDA(successorWallet.publicKey)
+    var successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    const postIndex = successorChatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+
+    //Turn decay off so the vote weights can be checked exactly
+    await program.methods.updateReputationConfig
+    (
+      originalReputationConfig.upVoteWeight, originalReputationConfig.downVoteWeight,
+      originalReputationConfig.starWeight, originalReputationConfig.fedWeight,
+      new anchor.BN(0)
+    ).rpc()
+
+    var expectedReputation = new anchor.BN(0)
+
+    for(const amount of [voteAmount, negativeVoteAmount])
+    {
+      await program.methods.postVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: successorWallet.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(amount)
+      }).accounts(feeTokenAccounts()).rpc()
+
+      const voteWeight = amount > 0 ? originalReputationConfig.upVoteWeight : originalReputationConfig.downVoteWeight
+      expectedReputation = expectedReputation.add(voteWeight.mul(new anchor.BN(Math.abs(amount))))
+
+      successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+      assert(successorChatAccount.reputation.eq(expectedReputation))
+    }
+
+    //Reputation halves every second from here on
+    await program.methods.updateReputationConfig
+    (
+      originalReputationConfig.upVoteWeight, originalReputationConfig.downVoteWeight,
+      originalReputationConfig.starWeight, originalReputationConfig.fedWeight,
+      new anchor.BN(1)
+    ).rpc()
+
+    //Reputation halves once for every whole half life, rounding towards 0 like the program does
+    const getDecayedReputation = (reputation: anchor.BN, halfLives: number) => halfLives >= 127 ? new anchor.BN(0) : reputation.div(new anchor.BN(2).pow(new anchor.BN(halfLives)))
+    var reputationTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+
+    //Unstarring only takes back what is left of the star after decay, so nothing is left over
+    await program.methods.starPost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      successorWallet.publicKey,
+      postIndex
+    ).rpc()
+
+    successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    var starTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+    expectedReputation = getDecayedReputation(expectedReputation, starTimeStamp - reputationTimeStamp).add(originalReputationConfig.starWeight)
+    assert(successorChatAccount.reputation.eq(expectedReputation))
+
+    await sleep(3000) // Sleep for 3 seconds so the star decays
+
+    await program.methods.unstarPost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      successorWallet.publicKey,
+      postIndex
+    ).rpc()
+
+    successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    reputationTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+    expectedReputation = getDecayedReputation(expectedReputation, reputationTimeStamp - starTimeStamp).sub(getDecayedReputation(originalReputationConfig.starWeight, reputationTimeStamp - starTimeStamp))
+    assert(successorChatAccount.reputation.eq(expectedReputation))
+    assert(successorChatAccount.reputation.eq(new anchor.BN(0)))
+
+    //A star that has decayed for a while adds less than its weight to the next reputation change
+    await program.methods.starPost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      successorWallet.publicKey,
+      postIndex
+    ).rpc()
+
+    successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    starTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+    expectedReputation = getDecayedReputation(expectedReputation, starTimeStamp - reputationTimeStamp).add(originalReputationConfig.starWeight)
+    assert(successorChatAccount.reputation.eq(expectedReputation))
+
+    await sleep(3000) // Sleep for 3 seconds so the star decays
+
+    await program.methods.postVote
+    ({
+      chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+      canidateAddress: successorWallet.publicKey,
+      chatAccountPostCountIndex: postIndex,
+      tokenMintAddress: usdcMint.publicKey,
+      voteAmount: new anchor.BN(1)
+    }).accounts(feeTokenAccounts()).rpc()
+
+    successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    reputationTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+    expectedReputation = getDecayedReputation(expectedReputation, reputationTimeStamp - starTimeStamp).add(originalReputationConfig.upVoteWeight)
+    assert(successorChatAccount.reputation.eq(expectedReputation))
+    assert(successorChatAccount.reputation.lt(originalReputationConfig.starWeight.add(originalReputationConfig.upVoteWeight)))
+
+    //Taking the star back leaves the vote's reputation decayed on the same schedule as the star
+    await program.methods.unstarPost
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      successorWallet.publicKey,
+      postIndex
+    ).rpc()
+
+    successorChatAccount = await program.account.chatAccount.fetch(successorChatAccountPDA)
+    const unstarTimeStamp = successorChatAccount.reputationUpdateTimeStamp.toNumber()
+    expectedReputation = getDecayedReputation(expectedReputation, unstarTimeStamp - reputationTimeStamp).sub(getDecayedReputation(originalReputationConfig.starWeight, unstarTimeStamp - starTimeStamp))
+    assert(successorChatAccount.reputation.eq(expectedReputation))
+
+    await program.methods.updateReputationConfig
+    (
+      originalReputationConfig.upVoteWeight, originalReputationConfig.downVoteWeight,
+      originalReputationConfig.starWeight, originalReputationConfig.fedWeight,
+      originalReputationConfig.decayHalfLifeSeconds
+    ).rpc()
+  })
+
+  it("Sets Votes Paid With A Token-2022 Fee Token And A Transfer Fee Token, Checks The Treasury, Author And Refund Balances, And Then Withdraws The Treasury", async () => 
+  {
+    const token2022Mint = await Token.createMint
+    (
+      program.provider.connection,
+      testingWalletKeypair,
+      program.provider.publicKey,
+      null,
+      usdcTokenDecimalAmount,
+      TOKEN_2022_PROGRAM_ID
+    )
+    const transferFeeMintAddress = await createTransferFeeMint(usdcTokenDecimalAmount)
 
-        m4aLv4Replies = await program.account.m4ALv4Reply.all()
+    const feeTokens = [
+      {tokenMintAddress: token2022Mint.publicKey, transferFee: new anchor.BN(0)},
+      {tokenMintAddress: transferFeeMintAddress, transferFee: new anchor.BN(maximumTransferFee)}
+    ]
 
-        var starredM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+    const [feeSchedulePDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("feeSchedule")], program.programId)
+    const feeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+    const centsToTokenAmount = new anchor.BN(10).pow(new anchor.BN(usdcTokenDecimalAmount - 2))
+    const upVoteFeeAmount = feeSchedule.upVoteFee.mul(centsToTokenAmount)
+    const authorTipAmount = feeSchedule.authorTipShare.mul(centsToTokenAmount)
 
-        assert(starredM4AReply[0].account.isStarred == false)
-      }
+    await program.methods.setChatVoteMode(m4aChatName, true).rpc()
+    await program.methods.setVoteFeeRefundPolicy(true).rpc()
 
-      //FED Reply
-      await program.methods.fedM4ALv4Reply
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        newM4ALv4Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
-      ).rpc()
+    await program.methods.postComment
+    (
+      m4aChatName, m4aCommentSectionNamePrefix, commentSectionName,
+      usdcMint.publicKey, 
+      comment
+    ).accounts({...feeTokenAccounts(), signer: successorWallet.publicKey})
+    .signers([successorWallet])
+    .rpc()
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+    const successorChatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(successorWallet.publicKey))
+    const postIndex = successorChatAccount.commentAndReplyCount.sub(new anchor.BN(1))
 
-      var fedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+    for(const {tokenMintAddress, transferFee} of feeTokens)
+    {
+      //The sender pays the mint's transfer fee on top so the recipient gets the full amount
+      const addTransferFee = (amount: anchor.BN) => amount.isZero() ? amount : amount.add(transferFee)
 
-      assert(fedM4AReply[0].account.isFed == true)
+      const walletATA = await deriveWalletATA(program.provider.publicKey, tokenMintAddress, TOKEN_2022_PROGRAM_ID)
+      await createATAForWallet(testingWalletKeypair, tokenMintAddress, walletATA, TOKEN_2022_PROGRAM_ID)
+      await mintUSDCToWallet(tokenMintAddress, walletATA, TOKEN_2022_PROGRAM_ID)
 
-      //UnFED Reply
-      if(unFED)
-      {
-        await program.methods.unfedM4ALv4Reply
-        (
-          m4aCommentSectionNamePrefix, commentSectionName,
-          newM4ALv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
-        ).rpc()
+      const successorWalletATA = await deriveWalletATA(successorWallet.publicKey, tokenMintAddress, TOKEN_2022_PROGRAM_ID)
+      await createATAForWallet(successorWallet, tokenMintAddress, successorWalletATA, TOKEN_2022_PROGRAM_ID)
 
-        m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      await program.methods.addFeeTokenEntry(tokenMintAddress).accounts({tokenMint: tokenMintAddress}).rpc()
+      await program.methods.createTreasuryVault(tokenMintAddress).accounts(feeTokenAccounts(tokenMintAddress, TOKEN_2022_PROGRAM_ID)).rpc()
 
-        var fedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+      const treasuryVaultTokenAccount = getTreasuryVaultTokenAccountPDA(tokenMintAddress)
 
-        assert(fedM4AReply[0].account.isFed == false)
-      }
+      //Up vote twice
+      var walletBalance = await getTokenBalance(walletATA)
 
-      //Delete Reply
-      await program.methods.deleteM4ALv4Reply
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+      await program.methods.setPostVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: successorWallet.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: tokenMintAddress,
+        voteAmount: new anchor.BN(2)
+      }).accounts(feeTokenAccounts(tokenMintAddress, TOKEN_2022_PROGRAM_ID)).rpc()
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      assert((await getTokenBalance(treasuryVaultTokenAccount)).eq(upVoteFeeAmount.muln(2)))
+      assert((await getTokenBalance(successorWalletATA)).eq(authorTipAmount.muln(2)))
+      assert((await getTokenBalance(walletATA)).eq(walletBalance.sub(addTransferFee(upVoteFeeAmount.muln(2))).sub(addTransferFee(authorTipAmount.muln(2)))))
 
-      var deletedM4AReply = m4aLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newM4ALv4Reply.id))
+      //Lower the vote to one so one up vote's fee is refunded, author tips are never refunded
+      walletBalance = await getTokenBalance(walletATA)
 
-      assert(deletedM4AReply[0].account.isDeleted == true)
+      await program.methods.setPostVote
+      ({
+        chatName: m4aChatName, commentSectionNamePrefix: m4aCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: successorWallet.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: tokenMintAddress,
+        voteAmount: new anchor.BN(1)
+      }).accounts(feeTokenAccounts(tokenMintAddress, TOKEN_2022_PROGRAM_ID)).rpc()
 
-      //Reply To Reply
-      const replyToLv4Reply = "Why you delete reply to reply to reply? :0"
+      const treasuryVaultBalance = upVoteFeeAmount.muln(2).sub(addTransferFee(upVoteFeeAmount))
 
-      await program.methods.replyToM4ALv4Reply
-      (
-        m4aCommentSectionNamePrefix, commentSectionName,
-        deletedM4AReply[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        replyToLv4Reply
-      ).rpc()
+      assert((await getTokenBalance(treasuryVaultTokenAccount)).eq(treasuryVaultBalance))
+      assert((await getTokenBalance(successorWalletATA)).eq(authorTipAmount.muln(2)))
+      assert((await getTokenBalance(walletATA)).eq(walletBalance.add(upVoteFeeAmount)))
 
-      var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+      //Withdraw everything left in the vault, the transfer fee comes out of the vault on top of the withdraw amount
+      walletBalance = await getTokenBalance(walletATA)
+      const withdrawAmount = treasuryVaultBalance.sub(transferFee)
 
-      m4aLv4Replies = await program.account.m4ALv4Reply.all()
+      await program.methods.withdrawTreasury(tokenMintAddress, withdrawAmount).accounts(feeTokenAccounts(tokenMintAddress, TOKEN_2022_PROGRAM_ID)).rpc()
 
-      var replyToM4ALv4Reply = m4aLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
+      assert((await getTokenBalance(treasuryVaultTokenAccount)).eq(new anchor.BN(0)))
+      assert((await getTokenBalance(walletATA)).eq(walletBalance.add(withdrawAmount)))
 
-      assert(replyToM4ALv4Reply[0].account.msg == replyToLv4Reply)
+      const treasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(tokenMintAddress))
+      assert(treasuryVault.withdrawnAmount.eq(treasuryVaultBalance))
     }
+
+    await program.methods.setVoteFeeRefundPolicy(false).rpc()
+    await program.methods.setChatVoteMode(m4aChatName, false).rpc()
   })
 
-  it("Initializes PLI Chat", async () => 
+  it("Creates PLI Chat", async () => 
   {
-    await program.methods.initializePliChat().rpc()
+    await program.methods.createChat(pliChatName, maxPostDepth).rpc()
   })
 
   it("Creates A PLI Comment Section", async () => 
@@ -1165,7 +2365,7 @@ describe("Chat_Protocol", () =>
       pliCommentSectionNamePrefix, commentSectionName,
       usdcMint.publicKey,
       new anchor.BN(voteAmount)
-    ).rpc()
+    ).accounts(feeTokenAccounts()).rpc()
 
     var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
 
@@ -1182,7 +2382,7 @@ describe("Chat_Protocol", () =>
         pliCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         new anchor.BN(negativeVoteAmount)
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
       commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
 
@@ -1193,6 +2393,63 @@ describe("Chat_Protocol", () =>
     }
   })
 
+  it("Switches The PLI Comment Section To Quadratic Vote Pricing, Votes Twice For The Video/Page, And Then Switches Back", async () => 
+  {
+    await program.methods.setCommentSectionVotePricingMode(pliCommentSectionNamePrefix, commentSectionName, quadraticVotePricingMode).rpc()
+
+    const [feeSchedulePDA] = anchor.web3.PublicKey.findProgramAddressSync([utf8.encode("feeSchedule")], program.programId)
+    const feeSchedule = await program.account.feeSchedule.fetch(feeSchedulePDA)
+    const centsToTokenAmount = 10 ** (usdcTokenDecimalAmount - 2)
+
+    //Quadratic pricing needs the vote tally
+    var errorMessage = ""
+
+    try
+    {
+      await program.methods.commentSectionVote
+      (
+        pliCommentSectionNamePrefix, commentSectionName,
+        usdcMint.publicKey, 
+        new anchor.BN(quadraticVoteAmount)
+      ).accounts(feeTokenAccounts()).rpc()
+    }
+    catch(error)
+    {
+      errorMessage = error.error.errorMessage
+    }
+
+    assert(errorMessage == missingVoteTallyErrorMsg)
+
+    //Quadratic pricing counts the up votes already cast on the PLI video/page, linear votes aren't tallied
+    const voteTallyPDA = getVoteTallyPDA(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName), program.provider.publicKey)
+    var votesAlreadyCast = 0
+
+    for(var i=1; i<=2; i++)
+    {
+      const voteCostUnits = (votesAlreadyCast + quadraticVoteAmount) ** 2 - votesAlreadyCast ** 2
+      const treasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(usdcMint.publicKey))
+
+      await program.methods.commentSectionVote
+      (
+        pliCommentSectionNamePrefix, commentSectionName,
+        usdcMint.publicKey, 
+        new anchor.BN(quadraticVoteAmount)
+      ).accounts({...feeTokenAccounts(), voteTally: voteTallyPDA}).rpc()
+
+      const updatedTreasuryVault = await program.account.treasuryVault.fetch(getTreasuryVaultPDA(usdcMint.publicKey))
+      const expectedRevenue = feeSchedule.videoVoteFee.mul(new anchor.BN(voteCostUnits * centsToTokenAmount))
+
+      assert(updatedTreasuryVault.videoVoteRevenue.sub(treasuryVault.videoVoteRevenue).eq(expectedRevenue))
+
+      votesAlreadyCast += quadraticVoteAmount
+    }
+
+    const voteTally = await program.account.voteTally.fetch(voteTallyPDA)
+    assert(voteTally.upVoteTotal.eq(new anchor.BN(votesAlreadyCast)))
+
+    await program.methods.setCommentSectionVotePricingMode(pliCommentSectionNamePrefix, commentSectionName, linearVotePricingMode).rpc()
+  })
+
   it("Posts A PLI Comment, Edits, Up Votes, Down Votes, Stars, Implements Idea, Unimplements Idea, Edits Idea, UnStars, FEDs, UnFEDs, And Then Deletes PLI Comment", async () => 
   {
     //Post 100 Comments
@@ -1201,14 +2458,14 @@ describe("Chat_Protocol", () =>
       console.log("PLI Comment: ", i)
 
       //Post Comment
-      await program.methods.postPliComment
+      await program.methods.postComment
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         comment
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      var pliComments = await program.account.pliComment.all()
+      var pliComments = await fetchChatPosts(pliChatName, 0)
       
       var newPLIComment = pliComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -1219,31 +2476,31 @@ describe("Chat_Protocol", () =>
       //Edit Comment
       const editedText = "Edited Comment"
 
-      await program.methods.editPliComment
+      await program.methods.editPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLIComment[0].account.chatAccountPostCountIndex,
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      pliComments = await program.account.pliComment.all()
+      pliComments = await fetchChatPosts(pliChatName, 0)
 
       var editedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(editedPLIComment[0].account.msg == editedText)
 
       //Up Vote Comment
-      await program.methods.pliCommentVote
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        newPLIComment[0].account.postOwnerAddress,
-        newPLIComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newPLIComment[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: newPLIComment[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      pliComments = await program.account.pliComment.all()
+      pliComments = await fetchChatPosts(pliChatName, 0)
 
       var upVotedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -1252,16 +2509,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Comment
       if(postDownVote)
       {
-        await program.methods.pliCommentVote
-        (
-          pliCommentSectionNamePrefix, commentSectionName,
-          newPLIComment[0].account.postOwnerAddress,
-          newPLIComment[0].account.chatAccountPostCountIndex,
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newPLIComment[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: newPLIComment[0].account.chatAccountPostCountIndex,
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        pliComments = await program.account.pliComment.all()
+        pliComments = await fetchChatPosts(pliChatName, 0)
 
         var downVotedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -1269,14 +2526,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Comment
-      await program.methods.starPliComment
+      await program.methods.starPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLIComment[0].account.postOwnerAddress,
         newPLIComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      pliComments = await program.account.pliComment.all()
+      pliComments = await fetchChatPosts(pliChatName, 0)
 
       var starredPLIComment = pliComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == pliCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -1340,14 +2597,14 @@ describe("Chat_Protocol", () =>
       //Unstar Comment
       if(unStar)
       {
-        await program.methods.unstarPliComment
+        await program.methods.unstarPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           publicKey,
           newPLIComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        pliComments = await program.account.pliComment.all()
+        pliComments = await fetchChatPosts(pliChatName, 0)
 
         var starredPLIComment = pliComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == pliCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -1355,14 +2612,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Comment
-      await program.methods.fedPliComment
+      await program.methods.fedPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         publicKey,
         newPLIComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      pliComments = await program.account.pliComment.all()
+      pliComments = await fetchChatPosts(pliChatName, 0)
 
       var fedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == pliCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -1371,14 +2628,14 @@ describe("Chat_Protocol", () =>
       //UnFED Comment
       if(unFED)
       {
-        await program.methods.unfedPliComment
+        await program.methods.unfedPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           publicKey,
           newPLIComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        pliComments = await program.account.pliComment.all()
+        pliComments = await fetchChatPosts(pliChatName, 0)
 
         var fedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == pliCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -1386,14 +2643,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Comment
-      await program.methods.deletePliComment
+      await program.methods.deletePost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLIComment[0].account.chatAccountPostCountIndex,
         usdcMint.publicKey,
-      ).rpc()
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(pliCommentSectionNamePrefix, commentSectionName, newPLIComment[0].account.chatAccountPostCountIndex, program.provider.publicKey)}).rpc()
 
-      pliComments = await program.account.pliComment.all()
+      pliComments = await fetchChatPosts(pliChatName, 0)
 
       var deletedPLIComment = pliComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -1408,21 +2666,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("PLI Reply: ", i)
 
-      var pliComments= await program.account.pliComment.all()
+      var pliComments= await fetchChatPosts(pliChatName, 0)
       
       //Reply To Comment
-      await program.methods.replyToPliComment
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        pliComments[0].account.postOwnerAddress,
-        pliComments[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: pliComments[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: pliComments[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var pliReplies = await program.account.pliReply.all()
+      var pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var chatAccountReply = pliReplies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -1433,31 +2691,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editPliReply
+      await program.methods.editPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      pliReplies = await program.account.pliReply.all()
+      pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var editedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
       assert(editedPLIReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.pliReplyVote
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        pliReplies[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: pliReplies[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      pliReplies = await program.account.pliReply.all()
+      pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var upVotedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1466,16 +2724,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.pliReplyVote
-        (
-          pliCommentSectionNamePrefix, commentSectionName,
-          pliReplies[0].account.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: pliReplies[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        pliReplies = await program.account.pliReply.all()
+        pliReplies = await fetchChatPosts(pliChatName, 1)
 
         var downVotedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1483,14 +2741,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starPliReply
+      await program.methods.starPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLIReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliReplies = await program.account.pliReply.all()
+      pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var starredPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1554,14 +2812,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarPliReply
+        await program.methods.unstarPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLIReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliReplies = await program.account.pliReply.all()
+        pliReplies = await fetchChatPosts(pliChatName, 1)
 
         var starredPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1569,14 +2827,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedPliReply
+      await program.methods.fedPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLIReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliReplies = await program.account.pliReply.all()
+      pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var fedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1585,14 +2843,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedPliReply
+        await program.methods.unfedPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLIReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliReplies = await program.account.pliReply.all()
+        pliReplies = await fetchChatPosts(pliChatName, 1)
 
         var fedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1600,14 +2858,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deletePliReply
+      await program.methods.deletePost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(pliCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      pliReplies = await program.account.pliReply.all()
+      pliReplies = await fetchChatPosts(pliChatName, 1)
 
       var deletedPLIReply = pliReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLIReply.id))
 
@@ -1622,21 +2881,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("PLI Reply To Reply: ", i)
 
-      var pliReplies= await program.account.pliReply.all()
+      var pliReplies= await fetchChatPosts(pliChatName, 1)
 
       //Reply To Reply
-      await program.methods.replyToPliReply
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        pliReplies[0].account.postOwnerAddress,
-        pliReplies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: pliReplies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: pliReplies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var pliLv3Replies = await program.account.pliLv3Reply.all()
+      var pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var chatAccountLv3Reply = pliLv3Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -1647,31 +2906,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editPliLv3Reply
+      await program.methods.editPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      pliLv3Replies = await program.account.pliLv3Reply.all()
+      pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var editedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
       assert(editedPLIReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.pliLv3ReplyVote
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        newPLILv3Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newPLILv3Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      pliLv3Replies = await program.account.pliLv3Reply.all()
+      pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var upVotedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1680,16 +2939,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.pliLv3ReplyVote
-        (
-          pliCommentSectionNamePrefix, commentSectionName,
-          newPLILv3Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newPLILv3Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        pliLv3Replies = await program.account.pliLv3Reply.all()
+        pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
         var downVotedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1697,14 +2956,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starPliLv3Reply
+      await program.methods.starPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLILv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliLv3Replies = await program.account.pliLv3Reply.all()
+      pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var starredPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1768,14 +3027,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarPliLv3Reply
+        await program.methods.unstarPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLILv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliLv3Replies = await program.account.pliLv3Reply.all()
+        pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
         var starredPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1783,14 +3042,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedPliLv3Reply
+      await program.methods.fedPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLILv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliLv3Replies = await program.account.pliLv3Reply.all()
+      pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var fedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1799,14 +3058,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedPliLv3Reply
+        await program.methods.unfedPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLILv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliLv3Replies = await program.account.pliLv3Reply.all()
+        pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
         var fedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1814,14 +3073,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deletePliLv3Reply
+      await program.methods.deletePost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
-      ).rpc()
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(pliCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      pliLv3Replies = await program.account.pliLv3Reply.all()
+      pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       var deletedPLIReply = pliLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv3Reply.id))
 
@@ -1836,21 +3096,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("PLI Reply To Reply To Reply: ", i)
 
-      var pliLv3Replies = await program.account.pliLv3Reply.all()
+      var pliLv3Replies = await fetchChatPosts(pliChatName, 2)
 
       //Reply To Reply
-      await program.methods.replyToPliLv3Reply
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        pliLv3Replies[0].account.postOwnerAddress,
-        pliLv3Replies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: pliLv3Replies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: pliLv3Replies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var pliLv4Replies = await program.account.pliLv4Reply.all()
+      var pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var chatAccountLv4Reply = pliLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -1861,31 +3121,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editPliLv4Reply
+      await program.methods.editPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var editedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
       assert(editedPLIReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.pliLv4ReplyVote
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        newPLILv4Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newPLILv4Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var upVotedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -1894,16 +3154,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.pliLv4ReplyVote
-        (
-          pliCommentSectionNamePrefix, commentSectionName,
-          newPLILv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newPLILv4Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        pliLv4Replies = await program.account.pliLv4Reply.all()
+        pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
         var downVotedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -1911,14 +3171,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starPliLv4Reply
+      await program.methods.starPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLILv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var starredPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -1982,14 +3242,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarPliLv4Reply
+        await program.methods.unstarPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLILv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliLv4Replies = await program.account.pliLv4Reply.all()
+        pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
         var starredPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -1997,14 +3257,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedPliLv4Reply
+      await program.methods.fedPost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         newPLILv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var fedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -2013,14 +3273,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedPliLv4Reply
+        await program.methods.unfedPost
         (
-          pliCommentSectionNamePrefix, commentSectionName,
+          pliChatName, pliCommentSectionNamePrefix, commentSectionName,
           newPLILv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        pliLv4Replies = await program.account.pliLv4Reply.all()
+        pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
         var fedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -2028,14 +3288,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deletePliLv4Reply
+      await program.methods.deletePost
       (
-        pliCommentSectionNamePrefix, commentSectionName,
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(pliCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 3)
 
       var deletedPLIReply = pliLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newPLILv4Reply.id))
 
@@ -2044,18 +3305,18 @@ describe("Chat_Protocol", () =>
       //Reply To Reply
       const replyToLv4Reply = "Why you delete reply to reply to reply? :0"
 
-      await program.methods.replyToPliLv4Reply
-      (
-        pliCommentSectionNamePrefix, commentSectionName,
-        deletedPLIReply[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        replyToLv4Reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: deletedPLIReply[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        msg: replyToLv4Reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      pliLv4Replies = await program.account.pliLv4Reply.all()
+      pliLv4Replies = await fetchChatPosts(pliChatName, 4)
 
       var replyToPLILv4Reply = pliLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -2063,9 +3324,96 @@ describe("Chat_Protocol", () =>
     }
   })
 
-  it("Initializes About Chat", async () => 
+  it("Verifies That An Older PLI Comment With More Votes Trends Below A Newer One, And That Deleting, Moderator Deleting And Closing The Comments Takes Them Off The Trending List", async () => 
+  {
+    const trendingPostAddresses: PublicKey[] = []
+
+    //The older comment gets 1 more vote than the newer one, which isn't worth the 6 seconds between them
+    for(const amount of [1001, 1000])
+    {
+      await program.methods.postComment
+      (
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
+        usdcMint.publicKey, 
+        comment
+      ).accounts(feeTokenAccounts()).rpc()
+
+      const chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
+      const postIndex = chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
+
+      await program.methods.postVote
+      ({
+        chatName: pliChatName, commentSectionNamePrefix: pliCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: program.provider.publicKey,
+        chatAccountPostCountIndex: postIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(amount)
+      }).accounts(feeTokenAccounts()).rpc()
+
+      trendingPostAddresses.push(getPostPDA(pliChatName, pliCommentSectionNamePrefix, commentSectionName, postIndex, program.provider.publicKey))
+
+      if(amount == 1001)
+      {
+        await sleep(6000) // Sleep for 6 seconds so the newer comment is newer
+      }
+    }
+
+    const [olderPostAddress, newerPostAddress] = trendingPostAddresses
+
+    var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.trendingPosts[0].postAddress.equals(newerPostAddress))
+    assert(commentSection.trendingPosts[1].postAddress.equals(olderPostAddress))
+
+    const newerPost = await program.account.post.fetch(newerPostAddress)
+
+    await program.methods.deletePost
+    (
+      pliChatName, pliCommentSectionNamePrefix, commentSectionName,
+      newerPost.chatAccountPostCountIndex,
+      usdcMint.publicKey,
+      storeContentHash
+    ).accounts(feeTokenAccounts()).rpc()
+
+    commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.trendingPosts.every((trendingPost: { postAddress: PublicKey }) => !trendingPost.postAddress.equals(newerPostAddress)))
+    assert(commentSection.trendingPosts[0].postAddress.equals(olderPostAddress))
+
+    //A moderator deleting a trending comment takes it off the trending list too
+    const olderPost = await program.account.post.fetch(olderPostAddress)
+
+    await program.methods.moderatorDeletePost
+    (
+      pliChatName, pliCommentSectionNamePrefix, commentSectionName,
+      program.provider.publicKey,
+      olderPost.chatAccountPostCountIndex,
+      removalReasonCode
+    ).rpc()
+
+    commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.trendingPosts.every((trendingPost: { postAddress: PublicKey }) => !trendingPost.postAddress.equals(olderPostAddress)))
+
+    //Closing the deleted comments keeps them off the trending list
+    for(const post of [newerPost, olderPost])
+    {
+      await closePostRevisions(pliChatName, pliCommentSectionNamePrefix, commentSectionName, post.chatAccountPostCountIndex)
+
+      await program.methods.closePost
+      (
+        pliChatName, pliCommentSectionNamePrefix, commentSectionName,
+        post.chatAccountPostCountIndex
+      ).rpc()
+    }
+
+    assert(await program.account.post.fetchNullable(newerPostAddress) == null)
+    assert(await program.account.post.fetchNullable(olderPostAddress) == null)
+
+    commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(pliCommentSectionNamePrefix, commentSectionName))
+    assert(commentSection.trendingPosts.every((trendingPost: { postAddress: PublicKey }) => !trendingPost.postAddress.equals(newerPostAddress) && !trendingPost.postAddress.equals(olderPostAddress)))
+  })
+
+  it("Creates About Chat", async () => 
   {
-    await program.methods.initializeAboutChat().rpc()
+    await program.methods.createChat(aboutChatName, maxPostDepth).rpc()
   })
 
   it("Creates An About Comment Section", async () => 
@@ -2081,7 +3429,7 @@ describe("Chat_Protocol", () =>
       aboutCommentSectionNamePrefix, commentSectionName,
       usdcMint.publicKey,
       new anchor.BN(voteAmount)
-    ).rpc()
+    ).accounts(feeTokenAccounts()).rpc()
 
     var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(aboutCommentSectionNamePrefix, commentSectionName))
 
@@ -2098,7 +3446,7 @@ describe("Chat_Protocol", () =>
         aboutCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         new anchor.BN(negativeVoteAmount)
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
       commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(aboutCommentSectionNamePrefix, commentSectionName))
 
@@ -2117,14 +3465,14 @@ describe("Chat_Protocol", () =>
       console.log("About Comment: ", i)
 
       //Post Comment
-      await program.methods.postAboutComment
+      await program.methods.postComment
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         comment
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      var aboutComments = await program.account.aboutComment.all()
+      var aboutComments = await fetchChatPosts(aboutChatName, 0)
       
       var newAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -2135,31 +3483,31 @@ describe("Chat_Protocol", () =>
       //Edit Comment
       const editedText = "Edited Comment"
 
-      await program.methods.editAboutComment
+      await program.methods.editPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutComment[0].account.chatAccountPostCountIndex,
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      aboutComments = await program.account.aboutComment.all()
+      aboutComments = await fetchChatPosts(aboutChatName, 0)
 
       var editedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(editedAboutComment[0].account.msg == editedText)
 
       //Up Vote Comment
-      await program.methods.aboutCommentVote
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        newAboutComment[0].account.postOwnerAddress,
-        newAboutComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newAboutComment[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: newAboutComment[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      aboutComments = await program.account.aboutComment.all()
+      aboutComments = await fetchChatPosts(aboutChatName, 0)
 
       var upVotedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -2168,16 +3516,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Comment
       if(postDownVote)
       {
-        await program.methods.aboutCommentVote
-        (
-          aboutCommentSectionNamePrefix, commentSectionName,
-          newAboutComment[0].account.postOwnerAddress,
-          newAboutComment[0].account.chatAccountPostCountIndex,
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newAboutComment[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: newAboutComment[0].account.chatAccountPostCountIndex,
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        aboutComments = await program.account.aboutComment.all()
+        aboutComments = await fetchChatPosts(aboutChatName, 0)
 
         var downVotedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -2185,14 +3533,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Comment
-      await program.methods.starAboutComment
+      await program.methods.starPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutComment[0].account.postOwnerAddress,
         newAboutComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      aboutComments = await program.account.aboutComment.all()
+      aboutComments = await fetchChatPosts(aboutChatName, 0)
 
       var starredAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == aboutCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -2256,14 +3604,14 @@ describe("Chat_Protocol", () =>
       //Unstar Comment
       if(unStar)
       {
-        await program.methods.unstarAboutComment
+        await program.methods.unstarPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutComment[0].account.postOwnerAddress,
           newAboutComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        aboutComments = await program.account.aboutComment.all()
+        aboutComments = await fetchChatPosts(aboutChatName, 0)
 
         var starredAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == aboutCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -2271,14 +3619,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Comment
-      await program.methods.fedAboutComment
+      await program.methods.fedPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutComment[0].account.postOwnerAddress,
         newAboutComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      aboutComments = await program.account.aboutComment.all()
+      aboutComments = await fetchChatPosts(aboutChatName, 0)
 
       var fedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == aboutCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -2287,14 +3635,14 @@ describe("Chat_Protocol", () =>
       //UnFED Comment
       if(unFED)
       {
-        await program.methods.unfedAboutComment
+        await program.methods.unfedPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutComment[0].account.postOwnerAddress,
           newAboutComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        aboutComments = await program.account.aboutComment.all()
+        aboutComments = await fetchChatPosts(aboutChatName, 0)
 
         var fedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == aboutCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -2302,14 +3650,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Comment
-      await program.methods.deleteAboutComment
+      await program.methods.deletePost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(aboutCommentSectionNamePrefix, commentSectionName, newAboutComment[0].account.chatAccountPostCountIndex, program.provider.publicKey)}).rpc()
 
-      aboutComments = await program.account.aboutComment.all()
+      aboutComments = await fetchChatPosts(aboutChatName, 0)
 
       var deletedAboutComment = aboutComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -2324,21 +3673,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("About Reply: ", i)
 
-      var aboutComments= await program.account.aboutComment.all()
+      var aboutComments= await fetchChatPosts(aboutChatName, 0)
       
       //Reply To Comment
-      await program.methods.replyToAboutComment
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        aboutComments[0].account.postOwnerAddress,
-        aboutComments[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: aboutComments[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: aboutComments[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var aboutReplies = await program.account.aboutReply.all()
+      var aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var chatAccountReply = aboutReplies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -2349,31 +3698,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editAboutReply
+      await program.methods.editPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      aboutReplies = await program.account.aboutReply.all()
+      aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var editedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
       assert(editedAboutReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.aboutReplyVote
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        aboutReplies[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: aboutReplies[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      aboutReplies = await program.account.aboutReply.all()
+      aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var upVotedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2382,16 +3731,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.aboutReplyVote
-        (
-          aboutCommentSectionNamePrefix, commentSectionName,
-          aboutReplies[0].account.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: aboutReplies[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        aboutReplies = await program.account.aboutReply.all()
+        aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
         var downVotedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2399,14 +3748,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starAboutReply
+      await program.methods.starPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutReplies = await program.account.aboutReply.all()
+      aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var starredAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2470,14 +3819,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarAboutReply
+        await program.methods.unstarPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutReplies = await program.account.aboutReply.all()
+        aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
         var starredAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2485,14 +3834,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedAboutReply
+      await program.methods.fedPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutReplies = await program.account.aboutReply.all()
+      aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var fedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2501,14 +3850,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedAboutReply
+        await program.methods.unfedPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutReplies = await program.account.aboutReply.all()
+        aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
         var fedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2516,14 +3865,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteAboutReply
+      await program.methods.deletePost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(aboutCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      aboutReplies = await program.account.aboutReply.all()
+      aboutReplies = await fetchChatPosts(aboutChatName, 1)
 
       var deletedAboutReply = aboutReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutReply.id))
 
@@ -2538,21 +3888,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("About Reply To Reply: ", i)
 
-      var aboutReplies= await program.account.aboutReply.all()
+      var aboutReplies= await fetchChatPosts(aboutChatName, 1)
 
       //Reply To Reply
-      await program.methods.replyToAboutReply
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        aboutReplies[0].account.postOwnerAddress,
-        aboutReplies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: aboutReplies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: aboutReplies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      var aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var chatAccountLv3Reply = aboutLv3Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -2563,29 +3913,29 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editAboutLv3Reply
+      await program.methods.editPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var editedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
       assert(editedAboutReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.aboutLv3ReplyVote(
-        aboutCommentSectionNamePrefix, commentSectionName,
-        newAboutLv3Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)).rpc()
+      await program.methods.postVote({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newAboutLv3Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)}).accounts(feeTokenAccounts()).rpc()
 
-      aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var upVotedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2594,16 +3944,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.aboutLv3ReplyVote
-        (
-          aboutCommentSectionNamePrefix, commentSectionName,
-          newAboutLv3Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newAboutLv3Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        aboutLv3Replies = await program.account.aboutLv3Reply.all()
+        aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
         var downVotedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2611,14 +3961,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starAboutLv3Reply
+      await program.methods.starPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutLv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var starredAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2682,14 +4032,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarAboutLv3Reply
+        await program.methods.unstarPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutLv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutLv3Replies = await program.account.aboutLv3Reply.all()
+        aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
         var starredAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2697,14 +4047,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedAboutLv3Reply
+      await program.methods.fedPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutLv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var fedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2713,14 +4063,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedAboutLv3Reply
+        await program.methods.unfedPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutLv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutLv3Replies = await program.account.aboutLv3Reply.all()
+        aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
         var fedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2728,14 +4078,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteAboutLv3Reply
+      await program.methods.deletePost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(aboutCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       var deletedAboutReply = aboutLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv3Reply.id))
 
@@ -2750,21 +4101,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("About Reply To Reply To Reply: ", i)
 
-      var aboutLv3Replies = await program.account.aboutLv3Reply.all()
+      var aboutLv3Replies = await fetchChatPosts(aboutChatName, 2)
 
       //Reply To Reply
-      await program.methods.replyToAboutLv3Reply
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        aboutLv3Replies[0].account.postOwnerAddress,
-        aboutLv3Replies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: aboutLv3Replies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: aboutLv3Replies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      var aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var chatAccountLv4Reply = aboutLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -2775,31 +4126,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editAboutLv4Reply
+      await program.methods.editPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var editedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
       assert(editedAboutReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.aboutLv4ReplyVote
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        newAboutLv4Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newAboutLv4Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var upVotedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2808,16 +4159,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.aboutLv4ReplyVote
-        (
-          aboutCommentSectionNamePrefix, commentSectionName,
-          newAboutLv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newAboutLv4Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        aboutLv4Replies = await program.account.aboutLv4Reply.all()
+        aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
         var downVotedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2825,14 +4176,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starAboutLv4Reply
+      await program.methods.starPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutLv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var starredAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2896,14 +4247,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarAboutLv4Reply
+        await program.methods.unstarPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutLv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutLv4Replies = await program.account.aboutLv4Reply.all()
+        aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
         var starredAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2911,14 +4262,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedAboutLv4Reply
+      await program.methods.fedPost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         newAboutLv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var fedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2927,14 +4278,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedAboutLv4Reply
+        await program.methods.unfedPost
         (
-          aboutCommentSectionNamePrefix, commentSectionName,
+          aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
           newAboutLv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        aboutLv4Replies = await program.account.aboutLv4Reply.all()
+        aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
         var fedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2942,14 +4293,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteAboutLv4Reply
+      await program.methods.deletePost
       (
-        aboutCommentSectionNamePrefix, commentSectionName,
+        aboutChatName, aboutCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(aboutCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 3)
 
       var deletedAboutReply = aboutLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newAboutLv4Reply.id))
 
@@ -2958,18 +4310,18 @@ describe("Chat_Protocol", () =>
       //Reply To Reply
       const replyToLv4Reply = "Why you delete reply to reply to reply? :0"
 
-      await program.methods.replyToAboutLv4Reply
-      (
-        aboutCommentSectionNamePrefix, commentSectionName,
-        deletedAboutReply[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        replyToLv4Reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: aboutChatName, commentSectionNamePrefix: aboutCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: deletedAboutReply[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        msg: replyToLv4Reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      aboutLv4Replies = await program.account.aboutLv4Reply.all()
+      aboutLv4Replies = await fetchChatPosts(aboutChatName, 4)
 
       var replyToAboutLv4Reply = aboutLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -2977,9 +4329,9 @@ describe("Chat_Protocol", () =>
     }
   })
 
-  it("Initializes LO Chat", async () => 
+  it("Creates LO Chat", async () => 
   {
-    await program.methods.initializeLoChat().rpc()
+    await program.methods.createChat(loChatName, maxPostDepth).rpc()
   })
 
   it("Creates An LO Comment Section", async () => 
@@ -2995,7 +4347,7 @@ describe("Chat_Protocol", () =>
       loCommentSectionNamePrefix, commentSectionName,
       usdcMint.publicKey,
       new anchor.BN(voteAmount)
-    ).rpc()
+    ).accounts(feeTokenAccounts()).rpc()
 
     var commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(loCommentSectionNamePrefix, commentSectionName))
 
@@ -3012,7 +4364,7 @@ describe("Chat_Protocol", () =>
         loCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         new anchor.BN(negativeVoteAmount)
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
       commentSection = await program.account.commentSection.fetch(getCommentSectionPDA(loCommentSectionNamePrefix, commentSectionName))
 
@@ -3031,14 +4383,14 @@ describe("Chat_Protocol", () =>
       console.log("LO Comment: ", i)
 
       //Post Comment
-      await program.methods.postLoComment
+      await program.methods.postComment
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         usdcMint.publicKey,
         comment
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      var loComments = await program.account.loComment.all()
+      var loComments = await fetchChatPosts(loChatName, 0)
       
       var newLoComment = loComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -3049,31 +4401,31 @@ describe("Chat_Protocol", () =>
       //Edit Comment
       const editedText = "Edited Comment"
 
-      await program.methods.editLoComment
+      await program.methods.editPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoComment[0].account.chatAccountPostCountIndex,
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      loComments = await program.account.loComment.all()
+      loComments = await fetchChatPosts(loChatName, 0)
 
       var editedLoComment = loComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
       assert(editedLoComment[0].account.msg == editedText)
 
       //Up Vote Comment
-      await program.methods.loCommentVote
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        newLoComment[0].account.postOwnerAddress,
-        newLoComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newLoComment[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: newLoComment[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      loComments = await program.account.loComment.all()
+      loComments = await fetchChatPosts(loChatName, 0)
 
       var upVotedLoComment = loComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -3082,16 +4434,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Comment
       if(postDownVote)
       {
-        await program.methods.loCommentVote
-        (
-          loCommentSectionNamePrefix, commentSectionName,
-          newLoComment[0].account.postOwnerAddress,
-          newLoComment[0].account.chatAccountPostCountIndex,
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newLoComment[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: newLoComment[0].account.chatAccountPostCountIndex,
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        loComments = await program.account.loComment.all()
+        loComments = await fetchChatPosts(loChatName, 0)
 
         var downVotedLoComment = loComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -3099,14 +4451,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Comment
-      await program.methods.starLoComment
+      await program.methods.starPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoComment[0].account.postOwnerAddress,
         newLoComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      loComments = await program.account.loComment.all()
+      loComments = await fetchChatPosts(loChatName, 0)
 
       var starredLoComment = loComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == loCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -3170,14 +4522,14 @@ describe("Chat_Protocol", () =>
       //Unstar Comment
       if(unStar)
       {
-        await program.methods.unstarLoComment
+        await program.methods.unstarPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoComment[0].account.postOwnerAddress,
           newLoComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        loComments = await program.account.loComment.all()
+        loComments = await fetchChatPosts(loChatName, 0)
 
         var starredLoComment = loComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == loCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -3185,14 +4537,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Comment
-      await program.methods.fedLoComment
+      await program.methods.fedPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoComment[0].account.postOwnerAddress,
         newLoComment[0].account.chatAccountPostCountIndex
       ).rpc()
 
-      loComments = await program.account.loComment.all()
+      loComments = await fetchChatPosts(loChatName, 0)
 
       var fedLoComment = loComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == loCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -3201,14 +4553,14 @@ describe("Chat_Protocol", () =>
       //UnFED Comment
       if(unFED)
       {
-        await program.methods.unfedLoComment
+        await program.methods.unfedPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoComment[0].account.postOwnerAddress,
           newLoComment[0].account.chatAccountPostCountIndex
         ).rpc()
 
-        loComments = await program.account.loComment.all()
+        loComments = await fetchChatPosts(loChatName, 0)
 
         var fedLoComment = loComments.filter((comment: { account: { id: anchor.BN, commentSectionNamePrefix: String, commentSectionName: String }}  ) => ((comment.account.id.eq(new anchor.BN(i))) && ((comment.account.commentSectionNamePrefix == loCommentSectionNamePrefix) && (comment.account.commentSectionName == commentSectionName))))
 
@@ -3216,14 +4568,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Comment
-      await program.methods.deleteLoComment
+      await program.methods.deletePost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoComment[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(loCommentSectionNamePrefix, commentSectionName, newLoComment[0].account.chatAccountPostCountIndex, program.provider.publicKey)}).rpc()
 
-      loComments = await program.account.loComment.all()
+      loComments = await fetchChatPosts(loChatName, 0)
 
       var deletedLoComment = loComments.filter((comment: { account: { id: anchor.BN }}  ) => comment.account.id.eq(new anchor.BN(i)))
 
@@ -3238,21 +4591,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("LO Reply: ", i)
 
-      var loComments= await program.account.loComment.all()
+      var loComments= await fetchChatPosts(loChatName, 0)
       
       //Reply To Comment
-      await program.methods.replyToLoComment
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        loComments[0].account.postOwnerAddress,
-        loComments[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: loComments[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: loComments[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var loReplies = await program.account.loReply.all()
+      var loReplies = await fetchChatPosts(loChatName, 1)
 
       var chatAccountReply = loReplies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -3263,31 +4616,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editLoReply
+      await program.methods.editPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      loReplies = await program.account.loReply.all()
+      loReplies = await fetchChatPosts(loChatName, 1)
 
       var editedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
       assert(editedLoReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.loReplyVote
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        loReplies[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: loReplies[0].account.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      loReplies = await program.account.loReply.all()
+      loReplies = await fetchChatPosts(loChatName, 1)
 
       var upVotedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3296,16 +4649,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.loReplyVote
-        (
-          loCommentSectionNamePrefix, commentSectionName,
-          loReplies[0].account.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: loReplies[0].account.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
       
-        loReplies = await program.account.loReply.all()
+        loReplies = await fetchChatPosts(loChatName, 1)
 
         var downVotedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3313,14 +4666,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starLoReply
+      await program.methods.starPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loReplies = await program.account.loReply.all()
+      loReplies = await fetchChatPosts(loChatName, 1)
 
       var starredLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3384,14 +4737,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarLoReply
+        await program.methods.unstarPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loReplies = await program.account.loReply.all()
+        loReplies = await fetchChatPosts(loChatName, 1)
 
         var starredLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3399,14 +4752,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedLoReply
+      await program.methods.fedPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoReply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loReplies = await program.account.loReply.all()
+      loReplies = await fetchChatPosts(loChatName, 1)
 
       var fedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3415,14 +4768,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedLoReply
+        await program.methods.unfedPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoReply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loReplies = await program.account.loReply.all()
+        loReplies = await fetchChatPosts(loChatName, 1)
 
         var fedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3430,14 +4783,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteLoReply
+      await program.methods.deletePost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(loCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      loReplies = await program.account.loReply.all()
+      loReplies = await fetchChatPosts(loChatName, 1)
 
       var deletedLoReply = loReplies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoReply.id))
 
@@ -3452,21 +4806,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("LO Reply To Reply: ", i)
 
-      var loReplies= await program.account.loReply.all()
+      var loReplies= await fetchChatPosts(loChatName, 1)
 
       //Reply To Reply
-      await program.methods.replyToLoReply
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        loReplies[0].account.postOwnerAddress,
-        loReplies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: loReplies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: loReplies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var loLv3Replies = await program.account.loLv3Reply.all()
+      var loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var chatAccountLv3Reply = loLv3Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -3477,29 +4831,29 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editLoLv3Reply
+      await program.methods.editPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      loLv3Replies = await program.account.loLv3Reply.all()
+      loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var editedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
       assert(editedLoReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.loLv3ReplyVote(
-        loCommentSectionNamePrefix, commentSectionName,
-        newLoLv3Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)).rpc()
+      await program.methods.postVote({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newLoLv3Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)}).accounts(feeTokenAccounts()).rpc()
 
-      loLv3Replies = await program.account.loLv3Reply.all()
+      loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var upVotedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3508,16 +4862,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.loLv3ReplyVote
-        (
-          loCommentSectionNamePrefix, commentSectionName,
-          newLoLv3Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newLoLv3Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        loLv3Replies = await program.account.loLv3Reply.all()
+        loLv3Replies = await fetchChatPosts(loChatName, 2)
 
         var downVotedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3525,14 +4879,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starLoLv3Reply
+      await program.methods.starPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoLv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loLv3Replies = await program.account.loLv3Reply.all()
+      loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var starredLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3596,14 +4950,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarLoLv3Reply
+        await program.methods.unstarPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoLv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loLv3Replies = await program.account.loLv3Reply.all()
+        loLv3Replies = await fetchChatPosts(loChatName, 2)
 
         var starredLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3611,14 +4965,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedLoLv3Reply
+      await program.methods.fedPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoLv3Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loLv3Replies = await program.account.loLv3Reply.all()
+      loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var fedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3627,14 +4981,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedLoLv3Reply
+        await program.methods.unfedPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoLv3Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loLv3Replies = await program.account.loLv3Reply.all()
+        loLv3Replies = await fetchChatPosts(loChatName, 2)
 
         var fedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3642,14 +4996,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteLoLv3Reply
+      await program.methods.deletePost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(loCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      loLv3Replies = await program.account.loLv3Reply.all()
+      loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       var deletedLoReply = loLv3Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv3Reply.id))
 
@@ -3664,21 +5019,21 @@ describe("Chat_Protocol", () =>
     { 
       console.log("LO Reply To Reply To Reply: ", i)
 
-      var loLv3Replies = await program.account.loLv3Reply.all()
+      var loLv3Replies = await fetchChatPosts(loChatName, 2)
 
       //Reply To Reply
-      await program.methods.replyToLoLv3Reply
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        loLv3Replies[0].account.postOwnerAddress,
-        loLv3Replies[0].account.chatAccountPostCountIndex,
-        usdcMint.publicKey,
-        reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: loLv3Replies[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: loLv3Replies[0].account.chatAccountPostCountIndex,
+        tokenMintAddress: usdcMint.publicKey,
+        msg: reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      var loLv4Replies = await program.account.loLv4Reply.all()
+      var loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var chatAccountLv4Reply = loLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -3689,31 +5044,31 @@ describe("Chat_Protocol", () =>
       //Edit Reply
       const editedText = "Edited Reply"
 
-      await program.methods.editLoLv4Reply
+      await program.methods.editPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
         usdcMint.publicKey,
         editedText
-      ).rpc()
+      ).accounts(feeTokenAccounts()).rpc()
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var editedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
       assert(editedLoReply[0].account.msg == editedText)
 
       //Up Vote Reply
-      await program.methods.loLv4ReplyVote
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        newLoLv4Reply.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        new anchor.BN(voteAmount)
-      ).rpc()
+      await program.methods.postVote
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        canidateAddress: newLoLv4Reply.postOwnerAddress,
+        chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        voteAmount: new anchor.BN(voteAmount)
+      }).accounts(feeTokenAccounts()).rpc()
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var upVotedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3722,16 +5077,16 @@ describe("Chat_Protocol", () =>
       //Down Vote Reply
       if(postDownVote)
       {
-        await program.methods.loLv4ReplyVote
-        (
-          loCommentSectionNamePrefix, commentSectionName,
-          newLoLv4Reply.postOwnerAddress,
-          chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-          usdcMint.publicKey,
-          new anchor.BN(negativeVoteAmount)
-        ).rpc()
+        await program.methods.postVote
+        ({
+          chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+          canidateAddress: newLoLv4Reply.postOwnerAddress,
+          chatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+          tokenMintAddress: usdcMint.publicKey,
+          voteAmount: new anchor.BN(negativeVoteAmount)
+        }).accounts(feeTokenAccounts()).rpc()
 
-        loLv4Replies = await program.account.loLv4Reply.all()
+        loLv4Replies = await fetchChatPosts(loChatName, 3)
 
         var downVotedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3739,14 +5094,14 @@ describe("Chat_Protocol", () =>
       }
 
       //Star Reply
-      await program.methods.starLoLv4Reply
+      await program.methods.starPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoLv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var starredLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3810,14 +5165,14 @@ describe("Chat_Protocol", () =>
       //Unstar Reply
       if(unStar)
       {
-        await program.methods.unstarLoLv4Reply
+        await program.methods.unstarPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoLv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loLv4Replies = await program.account.loLv4Reply.all()
+        loLv4Replies = await fetchChatPosts(loChatName, 3)
 
         var starredLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3825,14 +5180,14 @@ describe("Chat_Protocol", () =>
       }
 
       //FED Reply
-      await program.methods.fedLoLv4Reply
+      await program.methods.fedPost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         newLoLv4Reply.postOwnerAddress,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
       ).rpc()
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var fedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3841,14 +5196,14 @@ describe("Chat_Protocol", () =>
       //UnFED Reply
       if(unFED)
       {
-        await program.methods.unfedLoLv4Reply
+        await program.methods.unfedPost
         (
-          loCommentSectionNamePrefix, commentSectionName,
+          loChatName, loCommentSectionNamePrefix, commentSectionName,
           newLoLv4Reply.postOwnerAddress,
           chatAccount.commentAndReplyCount.sub(new anchor.BN(1))
         ).rpc()
 
-        loLv4Replies = await program.account.loLv4Reply.all()
+        loLv4Replies = await fetchChatPosts(loChatName, 3)
 
         var fedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3856,14 +5211,15 @@ describe("Chat_Protocol", () =>
       }
 
       //Delete Reply
-      await program.methods.deleteLoLv4Reply
+      await program.methods.deletePost
       (
-        loCommentSectionNamePrefix, commentSectionName,
+        loChatName, loCommentSectionNamePrefix, commentSectionName,
         chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey
-      ).rpc()
+        usdcMint.publicKey,
+        storeContentHash
+      ).accounts({...feeTokenAccounts(), idea: unStar ? null : getIdeaPDA(loCommentSectionNamePrefix, commentSectionName, chatAccount.commentAndReplyCount.sub(new anchor.BN(1)), program.provider.publicKey)}).rpc()
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 3)
 
       var deletedLoReply = loLv4Replies.filter((reply: { account: { id: anchor.BN }}  ) => reply.account.id.eq(newLoLv4Reply.id))
 
@@ -3872,18 +5228,18 @@ describe("Chat_Protocol", () =>
       //Reply To Reply
       const replyToLv4Reply = "Why you delete reply to reply to reply? :0"
 
-      await program.methods.replyToLoLv4Reply
-      (
-        loCommentSectionNamePrefix, commentSectionName,
-        deletedLoReply[0].account.postOwnerAddress,
-        chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
-        usdcMint.publicKey,
-        replyToLv4Reply
-      ).rpc()
+      await program.methods.replyToPost
+      ({
+        chatName: loChatName, commentSectionNamePrefix: loCommentSectionNamePrefix, commentSectionName: commentSectionName,
+        parentPostOwnerAddress: deletedLoReply[0].account.postOwnerAddress,
+        parentChatAccountPostCountIndex: chatAccount.commentAndReplyCount.sub(new anchor.BN(1)),
+        tokenMintAddress: usdcMint.publicKey,
+        msg: replyToLv4Reply
+      }).accounts(feeTokenAccounts()).rpc()
 
       var chatAccount = await program.account.chatAccount.fetch(getChatAccountPDA(program.provider.publicKey))
 
-      loLv4Replies = await program.account.loLv4Reply.all()
+      loLv4Replies = await fetchChatPosts(loChatName, 4)
 
       var replyToLoLv4Reply = loLv4Replies.filter((reply: { account: { chatAccountPostCountIndex: anchor.BN }}  ) => reply.account.chatAccountPostCountIndex.eq(chatAccount.commentAndReplyCount.sub(new anchor.BN(1))))
 
@@ -3907,6 +5263,17 @@ describe("Chat_Protocol", () =>
     counter += 1
   }
 
+  function feeTokenAccounts(tokenMintAddress: PublicKey = usdcMint.publicKey, tokenProgramID: PublicKey = TOKEN_PROGRAM_ID)
+  {
+    return {tokenMint: tokenMintAddress, tokenProgram: tokenProgramID}
+  }
+
+  async function fetchChatPosts(chatName: string, depth: number)
+  {
+    const posts = await program.account.post.all()
+    return posts.filter((post: { account: { chatName: string, depth: number }}) => post.account.chatName == chatName && post.account.depth == depth)
+  }
+
   async function airDropSol(walletPublicKey: PublicKey)
   {
     let token_airdrop = await program.provider.connection.requestAirdrop(walletPublicKey, 
@@ -3921,18 +5288,18 @@ describe("Chat_Protocol", () =>
     })
   }
 
-  async function deriveWalletATA(walletPublicKey: PublicKey, tokenMintAddress: PublicKey)
+  async function deriveWalletATA(walletPublicKey: PublicKey, tokenMintAddress: PublicKey, tokenProgramID: PublicKey = TOKEN_PROGRAM_ID)
   {
     return await Token.getAssociatedTokenAddress
     (
       ASSOCIATED_TOKEN_PROGRAM_ID,
-      TOKEN_PROGRAM_ID,
+      tokenProgramID,
       tokenMintAddress,
       walletPublicKey
     )
   }
 
-  async function createATAForWallet(walletKeyPair: Keypair, tokenMintAddress: PublicKey, walletATA: PublicKey)
+  async function createATAForWallet(walletKeyPair: Keypair, tokenMintAddress: PublicKey, walletATA: PublicKey, tokenProgramID: PublicKey = TOKEN_PROGRAM_ID)
   {
     //1. Add createATA instruction to transaction
     const transaction = new Transaction().add
@@ -3940,7 +5307,7 @@ describe("Chat_Protocol", () =>
       Token.createAssociatedTokenAccountInstruction
       (
         ASSOCIATED_TOKEN_PROGRAM_ID,
-        TOKEN_PROGRAM_ID,
+        tokenProgramID,
         tokenMintAddress,
         walletATA,
         walletKeyPair.publicKey,
@@ -3964,14 +5331,14 @@ describe("Chat_Protocol", () =>
     await program.provider.connection.confirmTransaction(tx, 'processed')
   }
 
-  async function mintUSDCToWallet(tokenMintAddress: PublicKey, walletATA: PublicKey)
+  async function mintUSDCToWallet(tokenMintAddress: PublicKey, walletATA: PublicKey, tokenProgramID: PublicKey = TOKEN_PROGRAM_ID)
   {
     //1. Add createMintTo instruction to transaction
     const transaction = new Transaction().add
     (
       Token.createMintToInstruction
       (
-        TOKEN_PROGRAM_ID,
+        tokenProgramID,
         tokenMintAddress,
         walletATA,
         program.provider.publicKey,
@@ -3984,6 +5351,167 @@ describe("Chat_Protocol", () =>
     await program.provider.sendAndConfirm(transaction);
   }
 
+  async function createTransferFeeMint(decimals: number)
+  {
+    const mintKeypair = Keypair.generate()
+    const mintSpace = 278 //165 byte base, 1 byte account type, 4 byte extension header and 108 byte TransferFeeConfig
+    const mintRent = await program.provider.connection.getMinimumBalanceForRentExemption(mintSpace)
+
+    //Token-2022 InitializeTransferFeeConfig, the CEO is both the fee config and the withdraw withheld authority
+    const transferFeeConfigData = Buffer.alloc(2 + 33 + 33 + 2 + 8)
+    transferFeeConfigData.writeUInt8(26, 0)
+    transferFeeConfigData.writeUInt8(0, 1)
+    transferFeeConfigData.writeUInt8(1, 2)
+    program.provider.publicKey.toBuffer().copy(transferFeeConfigData, 3)
+    transferFeeConfigData.writeUInt8(1, 35)
+    program.provider.publicKey.toBuffer().copy(transferFeeConfigData, 36)
+    transferFeeConfigData.writeUInt16LE(transferFeeBasisPoints, 68)
+    transferFeeConfigData.writeBigUInt64LE(BigInt(maximumTransferFee), 70)
+
+    //Token-2022 InitializeMint2 with no freeze authority
+    const initializeMintData = Buffer.alloc(1 + 1 + 32 + 1)
+    initializeMintData.writeUInt8(20, 0)
+    initializeMintData.writeUInt8(decimals, 1)
+    program.provider.publicKey.toBuffer().copy(initializeMintData, 2)
+    initializeMintData.writeUInt8(0, 34)
+
+    const transaction = new Transaction().add
+    (
+      SystemProgram.createAccount
+      ({
+        fromPubkey: program.provider.publicKey,
+        newAccountPubkey: mintKeypair.publicKey,
+        space: mintSpace,
+        lamports: mintRent,
+        programId: TOKEN_2022_PROGRAM_ID
+      }),
+      new TransactionInstruction
+      ({
+        keys: [{pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true}],
+        programId: TOKEN_2022_PROGRAM_ID,
+        data: transferFeeConfigData
+      }),
+      new TransactionInstruction
+      ({
+        keys: [{pubkey: mintKeypair.publicKey, isSigner: false, isWritable: true}],
+        programId: TOKEN_2022_PROGRAM_ID,
+        data: initializeMintData
+      })
+    )
+
+    await program.provider.sendAndConfirm(transaction, [mintKeypair])
+
+    return mintKeypair.publicKey
+  }
+
+  async function getTokenBalance(tokenAccountAddress: PublicKey)
+  {
+    const tokenAccountBalance = await program.provider.connection.getTokenAccountBalance(tokenAccountAddress)
+    return new anchor.BN(tokenAccountBalance.value.amount)
+  }
+
+  function getIdeaPDA(commentSectionNamePrefix: string, commentSectionName: string, chatAccountPostCountIndex: anchor.BN, postOwnerAddress: PublicKey)
+  {
+    const [ideaPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("idea"),
+        new TextEncoder().encode(commentSectionNamePrefix),
+        new TextEncoder().encode(commentSectionName),
+        chatAccountPostCountIndex.toBuffer('le', 16),
+        postOwnerAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return ideaPDA
+  }
+
+  function getPostVoteBallotPDA(postAddress: PublicKey, voterAddress: PublicKey)
+  {
+    const [postVoteBallotPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("postVoteBallot"),
+        postAddress.toBuffer(),
+        voterAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return postVoteBallotPDA
+  }
+
+  function getPostPDA(chatName: string, commentSectionNamePrefix: string, commentSectionName: string, chatAccountPostCountIndex: anchor.BN, postOwnerAddress: PublicKey)
+  {
+    const [postPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("post"),
+        new TextEncoder().encode(chatName),
+        new TextEncoder().encode(commentSectionNamePrefix),
+        new TextEncoder().encode(commentSectionName),
+        chatAccountPostCountIndex.toBuffer('le', 16),
+        postOwnerAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return postPDA
+  }
+
+  async function closePostRevisions(chatName: string, commentSectionNamePrefix: string, commentSectionName: string, chatAccountPostCountIndex: anchor.BN)
+  {
+    const postAddress = getPostPDA(chatName, commentSectionNamePrefix, commentSectionName, chatAccountPostCountIndex, program.provider.publicKey)
+    const post = await program.account.post.fetch(postAddress)
+
+    //Revisions are closed one per call, newest first
+    for(var revisionNumber = post.revisionCount - 1; revisionNumber >= 0; revisionNumber--)
+    {
+      await program.methods.closePostRevision
+      (
+        chatName, commentSectionNamePrefix, commentSectionName,
+        chatAccountPostCountIndex
+      ).accounts({postRevision: getPostRevisionPDA(postAddress, revisionNumber)}).rpc()
+    }
+  }
+
+  function getPostRevisionPDA(postAddress: PublicKey, revisionNumber: number)
+  {
+    const [postRevisionPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("postRevision"),
+        postAddress.toBuffer(),
+        new anchor.BN(revisionNumber).toBuffer('le', 4)
+      ],
+      program.programId
+    )
+    return postRevisionPDA
+  }
+
+  function getPostTombstonePDA(postAddress: PublicKey)
+  {
+    const [postTombstonePDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("postTombstone"),
+        postAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return postTombstonePDA
+  }
+
+  function getProtocolConfigPDA()
+  {
+    const [protocolConfigPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        new TextEncoder().encode("protocolConfig")
+      ],
+      program.programId
+    )
+    return protocolConfigPDA
+  }
+
   function getChatProtocolCEOAccountPDA()
   {
     const [chatProtocolCEOPDA] = anchor.web3.PublicKey.findProgramAddressSync
@@ -3996,6 +5524,57 @@ describe("Chat_Protocol", () =>
     return chatProtocolCEOPDA
   }
 
+  function getTreasuryVaultPDA(tokenMintAddress: PublicKey)
+  {
+    const [treasuryVaultPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("treasuryVault"),
+        tokenMintAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return treasuryVaultPDA
+  }
+
+  function getTreasuryVaultTokenAccountPDA(tokenMintAddress: PublicKey)
+  {
+    const [treasuryVaultTokenAccountPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("treasuryVaultTokenAccount"),
+        tokenMintAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return treasuryVaultTokenAccountPDA
+  }
+
+  function getModeratorPDA(moderatorAddress: PublicKey)
+  {
+    const [moderatorPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("moderator"),
+        moderatorAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return moderatorPDA
+  }
+
+  function getPollStatsPDA()
+  {
+    const [pollStatsPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("pollStats")
+      ],
+      program.programId
+    )
+    return pollStatsPDA
+  }
+
   function getPollPDA(pollIndex: number)
   {
     const [pollPDA] = anchor.web3.PublicKey.findProgramAddressSync
@@ -4050,6 +5629,20 @@ describe("Chat_Protocol", () =>
     return commentSectionPDA
   }
 
+  function getVoteTallyPDA(targetAddress: PublicKey, voterAddress: PublicKey)
+  {
+    const [voteTallyPDA] = anchor.web3.PublicKey.findProgramAddressSync
+    (
+      [
+        utf8.encode("voteTally"),
+        targetAddress.toBuffer(),
+        voterAddress.toBuffer()
+      ],
+      program.programId
+    )
+    return voteTallyPDA
+  }
+
   function getNewTime()
   {
     var newDate = new Date()